@@ -0,0 +1,127 @@
+use std::env;
+
+use serenity::{
+    async_trait,
+    model::{
+        gateway::Ready,
+        interactions::{
+            ApplicationCommand,
+            ButtonStyle,
+            Interaction,
+            InteractionResponseType,
+            InteractionType,
+        },
+    },
+    prelude::*,
+};
+
+struct Handler;
+
+#[async_trait]
+impl EventHandler for Handler {
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        match interaction.kind {
+            // The `/vote` command was used, reply with a message with two buttons.
+            InteractionType::ApplicationCommand => {
+                let result = interaction
+                    .create_interaction_response(&ctx.http, |response| {
+                        response.kind(InteractionResponseType::ChannelMessageWithSource);
+                        response.interaction_response_data(|message| {
+                            // Components are sent in action rows, which hold up to five buttons.
+                            //
+                            // The `custom_id` of a button is sent back when it is clicked, and is
+                            // how the bot knows which button was used.
+                            message.content("Do you like pineapple on pizza?").components(|c| {
+                                c.create_action_row(|row| {
+                                    row.create_button(|b| {
+                                        b.style(ButtonStyle::Success).label("Yes").custom_id("yes")
+                                    })
+                                    .create_button(|b| {
+                                        b.style(ButtonStyle::Danger).label("No").custom_id("no")
+                                    })
+                                })
+                            })
+                        })
+                    })
+                    .await;
+
+                if let Err(why) = result {
+                    println!("Cannot respond to slash command: {}", why);
+                }
+            },
+            // A button was clicked, which sends an interaction of the `MessageComponent` type.
+            InteractionType::MessageComponent => {
+                let component = match interaction.as_component() {
+                    Some(component) => component,
+                    None => return,
+                };
+
+                let answer = match component.custom_id() {
+                    "yes" => "You like pineapple on pizza!",
+                    _ => "You do not like pineapple on pizza.",
+                };
+
+                // Responding with `UpdateMessage` edits the message the button is attached to,
+                // here removing the buttons so that the user cannot vote twice.
+                let result = component
+                    .update_message(&ctx.http, |message| message.content(answer).components(|c| c))
+                    .await;
+
+                if let Err(why) = result {
+                    println!("Cannot respond to component interaction: {}", why);
+                    return;
+                }
+
+                // The interaction token can still be used to send followup messages.
+                let result = interaction
+                    .create_followup(&ctx.http, false, |f| f.content("Thank you for voting!"))
+                    .await;
+
+                if let Err(why) = result {
+                    println!("Cannot send followup message: {}", why);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    async fn ready(&self, ctx: Context, ready: Ready) {
+        println!("{} is connected!", ready.user.name);
+
+        let command = ApplicationCommand::create_global_application_command(&ctx.http, |command| {
+            command.name("vote").description("Starts a vote")
+        })
+        .await;
+
+        if let Err(why) = command {
+            println!("Cannot create the slash command: {}", why);
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    // Configure the client with your Discord bot token in the environment.
+    let token = env::var("DISCORD_TOKEN").expect("Expected a token in the environment");
+
+    // The Application Id is usually the Bot User Id.
+    let application_id: u64 = env::var("APPLICATION_ID")
+        .expect("Expected an application id in the environment")
+        .parse()
+        .expect("application id is not a valid id");
+
+    // Build our client.
+    let mut client = Client::builder(token)
+        .event_handler(Handler)
+        .application_id(application_id)
+        .await
+        .expect("Error creating client");
+
+    // Finally, start a single shard, and start listening to events.
+    //
+    // Shards will automatically attempt to reconnect, and will perform
+    // exponential backoff until it reconnects.
+    if let Err(why) = client.start().await {
+        println!("Client error: {:?}", why);
+    }
+}
@@ -2,7 +2,164 @@ use std::collections::HashMap;
 
 use serde_json::{json, Value};
 
-use crate::{model::interactions::ApplicationCommandOptionType, utils};
+use crate::{
+    internal::prelude::*,
+    model::{
+        channel::ChannelType,
+        interactions::{
+            ApplicationCommandOption,
+            ApplicationCommandOptionType,
+            ApplicationCommandType,
+            ApplicationIntegrationType,
+            InteractionContextType,
+        },
+        Permissions,
+    },
+    utils,
+};
+
+/// The maximum number of options of a command, or of sub-options of an option.
+const MAX_OPTIONS: usize = 25;
+
+/// Whether a command of the given `type` is a context menu command, which
+/// cannot have a description or options.
+fn is_context_menu(kind: Option<&Value>) -> bool {
+    match kind.and_then(Value::as_u64) {
+        Some(kind) => kind != ApplicationCommandType::ChatInput as u64,
+        None => false,
+    }
+}
+
+/// The maximum length of a command or option name.
+const MAX_NAME_LEN: usize = 32;
+/// The maximum length of a command or option description.
+const MAX_DESCRIPTION_LEN: usize = 100;
+/// The maximum number of choices of an option.
+const MAX_CHOICES: usize = 25;
+/// The maximum length of a choice name, and of a string choice value.
+const MAX_CHOICE_LEN: usize = 100;
+
+fn check_len(
+    errors: &mut Vec<String>,
+    prefix: &str,
+    field: &str,
+    value: Option<&Value>,
+    max: usize,
+) {
+    let len = value.and_then(Value::as_str).map_or(0, |s| s.chars().count());
+
+    if len == 0 || len > max {
+        errors.push(format!(
+            "{}{} must be between 1 and {} characters, got {}",
+            prefix, field, max, len
+        ));
+    }
+}
+
+fn validate_option(errors: &mut Vec<String>, option: &Value) {
+    let name = option.get("name").and_then(Value::as_str).unwrap_or_default();
+    let prefix = format!("option `{}`: ", name);
+
+    check_len(errors, &prefix, "name", option.get("name"), MAX_NAME_LEN);
+    check_len(errors, &prefix, "description", option.get("description"), MAX_DESCRIPTION_LEN);
+
+    let kind = option.get("type").and_then(Value::as_u64);
+    let is_channel = kind == Some(ApplicationCommandOptionType::Channel as u64);
+    if option.get("channel_types").is_some() && !is_channel {
+        errors.push(format!("{}channel types can only be set on channel options", prefix));
+    }
+
+    if let Some(choices) = option.get("choices").and_then(Value::as_array) {
+        if choices.len() > MAX_CHOICES {
+            errors.push(format!(
+                "{}has {} choices, but at most {} are allowed",
+                prefix,
+                choices.len(),
+                MAX_CHOICES
+            ));
+        }
+
+        for choice in choices {
+            check_len(errors, &prefix, "choice name", choice.get("name"), MAX_CHOICE_LEN);
+
+            if let Some(value) = choice.get("value").and_then(Value::as_str) {
+                let len = value.chars().count();
+
+                if len > MAX_CHOICE_LEN {
+                    errors.push(format!(
+                        "{}string choice value must be at most {} characters, got {}",
+                        prefix, MAX_CHOICE_LEN, len
+                    ));
+                }
+            }
+        }
+    }
+
+    validate_options(errors, &prefix, option.get("options"), MAX_OPTIONS);
+}
+
+/// Checks a command, getting its fields through `get`.
+///
+/// If `partial` is set, as for edits, the name and description are only
+/// checked if they are set.
+fn validate_command<'a>(
+    errors: &mut Vec<String>,
+    prefix: &str,
+    get: impl Fn(&str) -> Option<&'a Value>,
+    partial: bool,
+) {
+    let check_field = |errors: &mut Vec<String>, field: &str, max: usize| {
+        let value = get(field);
+
+        if !partial || value.is_some() {
+            check_len(errors, prefix, field, value, max);
+        }
+    };
+
+    check_field(errors, "name", MAX_NAME_LEN);
+
+    let max_options = if is_context_menu(get("type")) {
+        0
+    } else {
+        check_field(errors, "description", MAX_DESCRIPTION_LEN);
+
+        MAX_OPTIONS
+    };
+
+    let options_prefix = if prefix.is_empty() { "command " } else { prefix };
+    validate_options(errors, options_prefix, get("options"), max_options);
+}
+
+fn validate_options(errors: &mut Vec<String>, prefix: &str, options: Option<&Value>, max: usize) {
+    let options = match options.and_then(Value::as_array) {
+        Some(options) => options,
+        None => return,
+    };
+
+    if options.len() > max {
+        errors.push(format!(
+            "{}has {} options, but at most {} are allowed",
+            prefix,
+            options.len(),
+            max
+        ));
+    }
+
+    for option in options {
+        validate_option(errors, option);
+    }
+}
+
+fn localizations_to_value(
+    localizations: impl IntoIterator<Item = (impl ToString, impl ToString)>,
+) -> Value {
+    let map = localizations
+        .into_iter()
+        .map(|(locale, text)| (locale.to_string(), Value::String(text.to_string())))
+        .collect();
+
+    Value::Object(map)
+}
 
 /// A builder for creating a new [`ApplicationCommandOption`].
 ///
@@ -38,6 +195,26 @@ impl CreateApplicationCommandOption {
         self
     }
 
+    /// Sets the localized names of the option, as pairs of a locale, such as
+    /// `"de"`, and the name in that locale.
+    pub fn name_localizations(
+        &mut self,
+        localizations: impl IntoIterator<Item = (impl ToString, impl ToString)>,
+    ) -> &mut Self {
+        self.0.insert("name_localizations", localizations_to_value(localizations));
+        self
+    }
+
+    /// Sets the localized descriptions of the option, as pairs of a locale
+    /// and the description in that locale.
+    pub fn description_localizations(
+        &mut self,
+        localizations: impl IntoIterator<Item = (impl ToString, impl ToString)>,
+    ) -> &mut Self {
+        self.0.insert("description_localizations", localizations_to_value(localizations));
+        self
+    }
+
     /// The first required option for the user to complete.
     ///
     /// **Note**: Only one option can be `default`.
@@ -57,7 +234,7 @@ impl CreateApplicationCommandOption {
     /// Interaction commands can optionally have a limited
     /// number of integer or string choices.
     ///
-    /// **Note**: There can be no more than 10 choices set.
+    /// **Note**: There can be no more than 25 choices set.
     pub fn add_int_choice<D: ToString>(&mut self, name: D, value: i32) -> &mut Self {
         let choice = json!({
             "name": name.to_string(),
@@ -74,6 +251,123 @@ impl CreateApplicationCommandOption {
         self.add_choice(choice)
     }
 
+    /// Adds a choice to a [`Number`] option.
+    ///
+    /// **Note**: There can be no more than 25 choices set.
+    ///
+    /// [`Number`]: crate::model::interactions::ApplicationCommandOptionType::Number
+    pub fn add_number_choice<D: ToString>(&mut self, name: D, value: f64) -> &mut Self {
+        let choice = json!({
+            "name": name.to_string(),
+            "value": value
+        });
+        self.add_choice(choice)
+    }
+
+    /// Adds a choice with a name translated for each of the given locales.
+    ///
+    /// The `value` must match the option's type, such as an integer for an
+    /// [`Integer`] option or a string for a [`String`] option.
+    ///
+    /// ```rust
+    /// # use serenity::builder::CreateApplicationCommandOption;
+    /// let mut option = CreateApplicationCommandOption::default();
+    /// option.add_choice_localized("Cat", "cat", vec![("de", "Katze"), ("fr", "Chat")]);
+    /// ```
+    ///
+    /// [`Integer`]: crate::model::interactions::ApplicationCommandOptionType::Integer
+    /// [`String`]: crate::model::interactions::ApplicationCommandOptionType::String
+    pub fn add_choice_localized<D: ToString>(
+        &mut self,
+        name: D,
+        value: impl Into<Value>,
+        localizations: impl IntoIterator<Item = (impl ToString, impl ToString)>,
+    ) -> &mut Self {
+        let choice = json!({
+            "name": name.to_string(),
+            "value": value.into(),
+            "name_localizations": localizations_to_value(localizations),
+        });
+        self.add_choice(choice)
+    }
+
+    /// Sets if autocompletion is enabled for the option, in which case
+    /// [`Autocomplete`] interactions are sent while the user is typing.
+    ///
+    /// **Note**: Only [`String`], [`Integer`] and [`Number`] options without
+    /// choices can have autocompletion.
+    ///
+    /// [`Autocomplete`]: crate::model::interactions::InteractionType::Autocomplete
+    /// [`String`]: crate::model::interactions::ApplicationCommandOptionType::String
+    /// [`Integer`]: crate::model::interactions::ApplicationCommandOptionType::Integer
+    /// [`Number`]: crate::model::interactions::ApplicationCommandOptionType::Number
+    pub fn autocomplete(&mut self, autocomplete: bool) -> &mut Self {
+        self.0.insert("autocomplete", Value::Bool(autocomplete));
+        self
+    }
+
+    /// Restricts the kinds of channels the user can pick for a [`Channel`]
+    /// option.
+    ///
+    /// **Note**: Only [`Channel`] options can have channel types, which is
+    /// checked by [`CreateApplicationCommand::validate`].
+    ///
+    /// [`Channel`]: crate::model::interactions::ApplicationCommandOptionType::Channel
+    pub fn channel_types(
+        &mut self,
+        channel_types: impl IntoIterator<Item = ChannelType>,
+    ) -> &mut Self {
+        let channel_types = channel_types
+            .into_iter()
+            .map(|kind| Value::Number(serde_json::Number::from(kind.num())))
+            .collect();
+
+        self.0.insert("channel_types", Value::Array(channel_types));
+        self
+    }
+
+    /// Sets the minimum value of an [`Integer`] option.
+    ///
+    /// [`Integer`]: crate::model::interactions::ApplicationCommandOptionType::Integer
+    pub fn min_int_value(&mut self, value: i64) -> &mut Self {
+        self.0.insert("min_value", Value::Number(serde_json::Number::from(value)));
+        self
+    }
+
+    /// Sets the maximum value of an [`Integer`] option.
+    ///
+    /// [`Integer`]: crate::model::interactions::ApplicationCommandOptionType::Integer
+    pub fn max_int_value(&mut self, value: i64) -> &mut Self {
+        self.0.insert("max_value", Value::Number(serde_json::Number::from(value)));
+        self
+    }
+
+    /// Sets the minimum value of a [`Number`] option.
+    ///
+    /// **Note**: A value that is not finite is ignored.
+    ///
+    /// [`Number`]: crate::model::interactions::ApplicationCommandOptionType::Number
+    pub fn min_number_value(&mut self, value: f64) -> &mut Self {
+        if let Some(number) = serde_json::Number::from_f64(value) {
+            self.0.insert("min_value", Value::Number(number));
+        }
+
+        self
+    }
+
+    /// Sets the maximum value of a [`Number`] option.
+    ///
+    /// **Note**: A value that is not finite is ignored.
+    ///
+    /// [`Number`]: crate::model::interactions::ApplicationCommandOptionType::Number
+    pub fn max_number_value(&mut self, value: f64) -> &mut Self {
+        if let Some(number) = serde_json::Number::from_f64(value) {
+            self.0.insert("max_value", Value::Number(number));
+        }
+
+        self
+    }
+
     fn add_choice(&mut self, value: Value) -> &mut Self {
         let choices = self.0.entry("choices").or_insert_with(|| Value::Array(Vec::new()));
         let choices_arr = choices.as_array_mut().expect("Must be an array");
@@ -84,6 +378,9 @@ impl CreateApplicationCommandOption {
 
     /// If the option is a [`SubCommand`] or [`SubCommandGroup`] nested options are its parameters.
     ///
+    /// **Note**: Options can only have up to 25 sub-options, which is checked
+    /// by [`CreateApplicationCommand::validate`].
+    ///
     /// [`SubCommand`]: crate::model::interactions::ApplicationCommandOptionType::SubCommand
     /// [`SubCommandGroup`]: crate::model::interactions::ApplicationCommandOptionType::SubCommandGroup
     pub fn create_sub_option<F>(&mut self, f: F) -> &mut Self
@@ -105,6 +402,73 @@ impl CreateApplicationCommandOption {
     }
 }
 
+impl From<ApplicationCommandOption> for CreateApplicationCommandOption {
+    fn from(option: ApplicationCommandOption) -> Self {
+        let mut builder = CreateApplicationCommandOption::default();
+        builder
+            .kind(option.kind)
+            .name(option.name)
+            .description(option.description)
+            .required(option.required);
+
+        if let Some(localizations) = option.name_localizations {
+            builder.name_localizations(localizations);
+        }
+
+        if let Some(localizations) = option.description_localizations {
+            builder.description_localizations(localizations);
+        }
+
+        if !option.choices.is_empty() {
+            let choices = option
+                .choices
+                .into_iter()
+                .map(|choice| match choice.name_localizations {
+                    Some(localizations) => json!({
+                        "name": choice.name,
+                        "name_localizations": localizations,
+                        "value": choice.value,
+                    }),
+                    None => json!({
+                        "name": choice.name,
+                        "value": choice.value,
+                    }),
+                })
+                .collect();
+
+            builder.0.insert("choices", Value::Array(choices));
+        }
+
+        if option.autocomplete {
+            builder.autocomplete(true);
+        }
+
+        if !option.channel_types.is_empty() {
+            let channel_types = option
+                .channel_types
+                .into_iter()
+                .map(|kind| Value::Number(serde_json::Number::from(kind.num())))
+                .collect();
+
+            builder.0.insert("channel_types", Value::Array(channel_types));
+        }
+
+        if let Some(min_value) = option.min_value {
+            builder.0.insert("min_value", min_value);
+        }
+
+        if let Some(max_value) = option.max_value {
+            builder.0.insert("max_value", max_value);
+        }
+
+        for sub_option in option.options {
+            builder.add_sub_option(sub_option.into());
+        }
+
+        builder
+    }
+}
+
 /// A builder for creating a new [`ApplicationCommand`].
 ///
 /// [`Self::name`] and [`Self::description`] are required fields.
@@ -123,27 +487,157 @@ impl CreateApplicationCommand {
         self
     }
 
+    /// Checks the command against the limits imposed by Discord, such as the
+    /// length of names and descriptions, and the number of options and
+    /// choices.
+    ///
+    /// This is done automatically whenever a command is created or edited
+    /// through [`ApplicationCommand`] or [`GuildId`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of every limit that is exceeded.
+    ///
+    /// [`ApplicationCommand`]: crate::model::interactions::ApplicationCommand
+    /// [`GuildId`]: crate::model::id::GuildId
+    pub fn validate(&self) -> StdResult<(), Vec<String>> {
+        self._validate(false)
+    }
+
+    /// Same as [`Self::validate`], but only checks the name and description
+    /// if they are set, as an edit may leave them out.
+    pub(crate) fn validate_edit(&self) -> StdResult<(), Vec<String>> {
+        self._validate(true)
+    }
+
+    fn _validate(&self, partial: bool) -> StdResult<(), Vec<String>> {
+        let mut errors = Vec::new();
+        validate_command(&mut errors, "", |field| self.0.get(field), partial);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Specify if the command should not be usable by default
     ///
     /// **Note**: Setting it to false will disable it for anyone,
     /// including administrators and guild owners.
+    #[deprecated(note = "use `default_member_permissions` instead")]
     pub fn default_permission(&mut self, default_permission: bool) -> &mut Self {
         self.0.insert("default_permission", Value::Bool(default_permission));
 
         self
     }
 
+    /// Specify the permissions a member needs to use the command by default.
+    ///
+    /// **Note**: Passing [`Permissions::empty`] makes the command usable only
+    /// by administrators. Guild administrators can override this for their
+    /// guild.
+    ///
+    /// [`Permissions::empty`]: crate::model::permissions::Permissions::empty
+    pub fn default_member_permissions(&mut self, permissions: Permissions) -> &mut Self {
+        self.0.insert("default_member_permissions", Value::String(permissions.bits().to_string()));
+        self
+    }
+
+    /// Specify if the command can be used in direct messages.
+    ///
+    /// **Note**: This has no effect on guild commands.
+    pub fn dm_permission(&mut self, dm_permission: bool) -> &mut Self {
+        self.0.insert("dm_permission", Value::Bool(dm_permission));
+        self
+    }
+
+    /// Specify the installation contexts the command is available in.
+    pub fn integration_types(
+        &mut self,
+        integration_types: Vec<ApplicationIntegrationType>,
+    ) -> &mut Self {
+        let integration_types = integration_types
+            .into_iter()
+            .map(|kind| Value::Number(serde_json::Number::from(kind as u8)))
+            .collect();
+
+        self.0.insert("integration_types", Value::Array(integration_types));
+        self
+    }
+
+    /// Specify the interaction contexts the command can be used in.
+    pub fn contexts(&mut self, contexts: Vec<InteractionContextType>) -> &mut Self {
+        let contexts = contexts
+            .into_iter()
+            .map(|kind| Value::Number(serde_json::Number::from(kind as u8)))
+            .collect();
+
+        self.0.insert("contexts", Value::Array(contexts));
+        self
+    }
+
+    /// Specify if the command is age-restricted.
+    ///
+    /// **Note**: Age-restricted commands can only be used in NSFW channels.
+    /// This is enforced by Discord, which will not send interactions for them
+    /// from other channels.
+    pub fn nsfw(&mut self, nsfw: bool) -> &mut Self {
+        self.0.insert("nsfw", Value::Bool(nsfw));
+        self
+    }
+
+    /// Specify the type of the Interaction.
+    ///
+    /// **Note**: This defaults to [`ApplicationCommandType::ChatInput`].
+    /// Context menu commands have no description, so it is removed if one
+    /// was already set.
+    pub fn kind(&mut self, kind: ApplicationCommandType) -> &mut Self {
+        self.0.insert("type", Value::Number(serde_json::Number::from(kind as u8)));
+
+        if is_context_menu(self.0.get("type")) {
+            self.0.remove("description");
+        }
+
+        self
+    }
+
     /// Specify the description of the Interaction.
     ///
-    /// **Note**: Must be between 1 and 100 characters long.
+    /// **Note**: Must be between 1 and 100 characters long. It is ignored for
+    /// context menu commands, which Discord does not allow a description for.
     pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
-        self.0.insert("description", Value::String(description.to_string()));
+        if !is_context_menu(self.0.get("type")) {
+            self.0.insert("description", Value::String(description.to_string()));
+        }
+
+        self
+    }
+
+    /// Specify the localized names of the Interaction, as pairs of a locale,
+    /// such as `"de"`, and the name in that locale.
+    pub fn name_localizations(
+        &mut self,
+        localizations: impl IntoIterator<Item = (impl ToString, impl ToString)>,
+    ) -> &mut Self {
+        self.0.insert("name_localizations", localizations_to_value(localizations));
+        self
+    }
+
+    /// Specify the localized descriptions of the Interaction, as pairs of a
+    /// locale and the description in that locale.
+    pub fn description_localizations(
+        &mut self,
+        localizations: impl IntoIterator<Item = (impl ToString, impl ToString)>,
+    ) -> &mut Self {
+        self.0.insert("description_localizations", localizations_to_value(localizations));
         self
     }
 
     /// Create an interaction option for the interaction.
     ///
-    /// **Note**: Interactions can only have up to 10 options.
+    /// **Note**: Interactions can only have up to 25 options, and context menu
+    /// commands cannot have any. This is checked by [`Self::validate`].
     pub fn create_option<F>(&mut self, f: F) -> &mut Self
     where
         F: FnOnce(&mut CreateApplicationCommandOption) -> &mut CreateApplicationCommandOption,
@@ -155,7 +649,7 @@ impl CreateApplicationCommand {
 
     /// Add an interaction option for the interaction.
     ///
-    /// **Note**: Interactions can only have up to 10 options.
+    /// **Note**: Interactions can only have up to 25 options.
     pub fn add_option(&mut self, option: CreateApplicationCommandOption) -> &mut Self {
         let new_option = utils::hashmap_to_json_map(option.0);
         let options = self.0.entry("options").or_insert_with(|| Value::Array(Vec::new()));
@@ -165,9 +659,20 @@ impl CreateApplicationCommand {
         self
     }
 
+    /// Add an existing [`ApplicationCommandOption`], such as one of a fetched
+    /// [`ApplicationCommand`], as an option for the interaction.
+    ///
+    /// **Note**: Interactions can only have up to 25 options.
+    ///
+    /// [`ApplicationCommandOption`]: crate::model::interactions::ApplicationCommandOption
+    /// [`ApplicationCommand`]: crate::model::interactions::ApplicationCommand
+    pub fn with_option(&mut self, option: ApplicationCommandOption) -> &mut Self {
+        self.add_option(option.into())
+    }
+
     /// Sets all the interaction options for the interaction.
     ///
-    /// **Note**: Interactions can only have up to 10 options.
+    /// **Note**: Interactions can only have up to 25 options.
     pub fn set_options(&mut self, options: Vec<CreateApplicationCommandOption>) -> &mut Self {
         let new_options = options
             .into_iter()
@@ -182,6 +687,36 @@ impl CreateApplicationCommand {
 pub struct CreateApplicationCommands(pub Vec<Value>);
 
 impl CreateApplicationCommands {
+    /// Checks every command with [`CreateApplicationCommand::validate`].
+    ///
+    /// This is done automatically when creating commands through
+    /// [`ApplicationCommand::create_global_application_commands`] or
+    /// [`GuildId::create_application_commands`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of every limit that is exceeded, prefixed with
+    /// the name of the command exceeding it.
+    ///
+    /// [`ApplicationCommand::create_global_application_commands`]: crate::model::interactions::ApplicationCommand::create_global_application_commands
+    /// [`GuildId::create_application_commands`]: crate::model::id::GuildId::create_application_commands
+    pub fn validate(&self) -> StdResult<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        for command in &self.0 {
+            let name = command.get("name").and_then(Value::as_str).unwrap_or_default();
+            let prefix = format!("command `{}`: ", name);
+
+            validate_command(&mut errors, &prefix, |field| command.get(field), false);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Creates a new application command.
     pub fn create_application_command<F>(&mut self, f: F) -> &mut Self
     where
@@ -221,3 +756,299 @@ impl CreateApplicationCommands {
         self
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_with_option() {
+        let option: ApplicationCommandOption = serde_json::from_value(json!({
+            "type": 1,
+            "name": "get",
+            "description": "Gets a tag",
+            "options": [{
+                "type": 3,
+                "name": "name",
+                "description": "The tag name",
+                "required": true,
+                "choices": [{"name": "Rust", "value": "rust"}],
+            }],
+        }))
+        .unwrap();
+
+        let mut command = CreateApplicationCommand::default();
+        command.with_option(option);
+
+        let map = utils::hashmap_to_json_map(command.0);
+
+        assert_eq!(
+            map["options"],
+            json!([{
+                "type": 1,
+                "name": "get",
+                "description": "Gets a tag",
+                "required": false,
+                "options": [{
+                    "type": 3,
+                    "name": "name",
+                    "description": "The tag name",
+                    "required": true,
+                    "choices": [{"name": "Rust", "value": "rust"}],
+                }],
+            }])
+        );
+    }
+
+    #[test]
+    fn test_number_choice() {
+        let mut option = CreateApplicationCommandOption::default();
+        option.kind(ApplicationCommandOptionType::Number).add_number_choice("Half", 0.5);
+
+        assert_eq!(option.0["choices"], json!([{"name": "Half", "value": 0.5}]));
+    }
+
+    #[test]
+    fn test_localizations() {
+        let mut command = CreateApplicationCommand::default();
+        command
+            .name("roll")
+            .name_localizations(vec![("de", "wuerfeln")])
+            .description("Rolls a die")
+            .description_localizations(vec![("de", "Wirft einen Wuerfel")]);
+
+        let map = utils::hashmap_to_json_map(command.0);
+
+        assert_eq!(map["name_localizations"], json!({"de": "wuerfeln"}));
+        assert_eq!(map["description_localizations"], json!({"de": "Wirft einen Wuerfel"}));
+
+        let option: ApplicationCommandOption = serde_json::from_value(json!({
+            "type": 3,
+            "name": "sides",
+            "description": "The number of sides",
+        }))
+        .unwrap();
+
+        assert!(option.name_localizations.is_none());
+        assert!(option.description_localizations.is_none());
+    }
+
+    #[test]
+    fn test_value_range() {
+        let mut option = CreateApplicationCommandOption::default();
+        option
+            .kind(ApplicationCommandOptionType::Integer)
+            .name("sides")
+            .description("The number of sides")
+            .min_int_value(2)
+            .max_int_value(100);
+
+        let value = Value::Object(utils::hashmap_to_json_map(option.0));
+        let option: ApplicationCommandOption = serde_json::from_value(value).unwrap();
+
+        assert_eq!(option.min_value, Some(json!(2)));
+        assert_eq!(option.max_value, Some(json!(100)));
+
+        let builder = CreateApplicationCommandOption::from(option);
+
+        assert_eq!(builder.0["min_value"], json!(2));
+        assert_eq!(builder.0["max_value"], json!(100));
+
+        let mut option = CreateApplicationCommandOption::default();
+        option.min_number_value(0.5).max_number_value(f64::NAN);
+
+        assert_eq!(option.0["min_value"], json!(0.5));
+        assert!(!option.0.contains_key("max_value"));
+    }
+
+    #[test]
+    fn test_autocomplete() {
+        let option: ApplicationCommandOption = serde_json::from_value(json!({
+            "type": 3,
+            "name": "tag",
+            "description": "The tag to get",
+            "autocomplete": true,
+        }))
+        .unwrap();
+
+        assert!(option.autocomplete);
+
+        let builder = CreateApplicationCommandOption::from(option);
+
+        assert_eq!(builder.0["autocomplete"], Value::Bool(true));
+    }
+
+    #[test]
+    fn test_channel_types() {
+        let mut option = CreateApplicationCommandOption::default();
+        option
+            .kind(ApplicationCommandOptionType::Channel)
+            .name("channel")
+            .channel_types(vec![ChannelType::Text, ChannelType::News]);
+
+        assert_eq!(option.0["channel_types"], json!([0, 5]));
+    }
+
+    #[test]
+    fn test_default_member_permissions() {
+        let mut command = CreateApplicationCommand::default();
+        command.default_member_permissions(Permissions::BAN_MEMBERS);
+
+        assert_eq!(command.0["default_member_permissions"], json!("4"));
+    }
+
+    #[test]
+    fn test_context_menu_command() {
+        let mut command = CreateApplicationCommand::default();
+        command.name("Quote").description("Quotes a message").kind(ApplicationCommandType::Message);
+
+        assert_eq!(command.0["type"], json!(3));
+        assert!(!command.0.contains_key("description"));
+
+        command.description("Quotes a message");
+        assert!(!command.0.contains_key("description"));
+
+        assert!(command.validate().is_ok());
+        command.create_option(|o| o.name("extra").description("Extra"));
+        assert_eq!(command.validate().unwrap_err(), vec![
+            "command has 1 options, but at most 0 are allowed".to_string()
+        ]);
+    }
+
+    #[test]
+    fn test_nsfw() {
+        let mut command = CreateApplicationCommand::default();
+        command.name("roll").description("Rolls a die").nsfw(true);
+
+        let map = utils::hashmap_to_json_map(command.0);
+
+        assert_eq!(map["nsfw"], Value::Bool(true));
+    }
+
+    #[test]
+    fn test_too_many_options() {
+        let mut command = CreateApplicationCommand::default();
+        command.name("many").description("Has many options");
+
+        for i in 0..MAX_OPTIONS {
+            command.create_option(|o| o.name(i).description("An option"));
+        }
+
+        assert!(command.validate().is_ok());
+
+        command.create_option(|o| o.name("extra").description("An option"));
+        assert_eq!(command.validate().unwrap_err(), vec![
+            "command has 26 options, but at most 25 are allowed".to_string()
+        ]);
+
+        let mut command = CreateApplicationCommand::default();
+        command.name("many").description("Has many sub-options");
+        command.create_option(|o| {
+            o.name("group").description("A group");
+
+            for i in 0..=MAX_OPTIONS {
+                o.create_sub_option(|o| o.name(i).description("An option"));
+            }
+
+            o
+        });
+
+        assert_eq!(command.validate().unwrap_err(), vec![
+            "option `group`: has 26 options, but at most 25 are allowed".to_string()
+        ]);
+    }
+
+    #[test]
+    fn test_choice_localized() {
+        let mut option = CreateApplicationCommandOption::default();
+        option.add_choice_localized("Cat", "cat", vec![("de", "Katze")]);
+        option.add_choice_localized("One", 1, Vec::<(String, String)>::new());
+
+        assert_eq!(
+            option.0["choices"],
+            json!([
+                {"name": "Cat", "value": "cat", "name_localizations": {"de": "Katze"}},
+                {"name": "One", "value": 1, "name_localizations": {}},
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_channel_types() {
+        let mut command = CreateApplicationCommand::default();
+        command.name("topic").description("Sets the topic");
+        command.create_option(|o| {
+            o.name("channel")
+                .description("The channel")
+                .channel_types(vec![ChannelType::Text])
+                .kind(ApplicationCommandOptionType::String)
+        });
+
+        assert_eq!(command.validate().unwrap_err(), vec![
+            "option `channel`: channel types can only be set on channel options".to_string()
+        ]);
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut command = CreateApplicationCommand::default();
+        command.name("animal").description("Picks an animal");
+        command.create_option(|o| {
+            o.name("kind").description("The kind of animal").add_string_choice("Cat", "cat")
+        });
+
+        assert!(command.validate().is_ok());
+
+        command.name("a".repeat(33));
+        command.create_option(|o| o.name("size").add_int_choice("", 1));
+
+        let errors = command.validate().unwrap_err();
+        assert_eq!(errors, vec![
+            "name must be between 1 and 32 characters, got 33".to_string(),
+            "option `size`: description must be between 1 and 100 characters, got 0".to_string(),
+            "option `size`: choice name must be between 1 and 100 characters, got 0".to_string(),
+        ]);
+
+        let mut menu = CreateApplicationCommand::default();
+        menu.name("Inspect").kind(ApplicationCommandType::User);
+
+        assert!(menu.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_edit() {
+        let mut command = CreateApplicationCommand::default();
+        command.description("Picks an animal");
+
+        assert!(command.validate().is_err());
+        assert!(command.validate_edit().is_ok());
+
+        command.description("");
+        assert_eq!(command.validate_edit().unwrap_err(), vec![
+            "description must be between 1 and 100 characters, got 0".to_string()
+        ]);
+    }
+
+    #[test]
+    fn test_validate_commands() {
+        let mut commands = CreateApplicationCommands::default();
+        commands.create_application_command(|c| c.name("ping").description("Pings"));
+
+        assert!(commands.validate().is_ok());
+
+        commands.create_application_command(|c| {
+            c.name("many").description("Has many options");
+
+            for i in 0..=MAX_OPTIONS {
+                c.create_option(|o| o.name(i).description("An option"));
+            }
+
+            c
+        });
+
+        assert_eq!(commands.validate().unwrap_err(), vec![
+            "command `many`: has 26 options, but at most 25 are allowed".to_string()
+        ]);
+    }
+}
@@ -2,9 +2,27 @@ use std::collections::HashMap;
 
 use serde_json::Value;
 
+use crate::internal::prelude::*;
 use crate::model::interactions::ApplicationCommandPermissionType;
+use crate::model::ModelError;
 use crate::utils;
 
+/// The maximum number of permissions of a single command.
+const MAX_PERMISSIONS: usize = 10;
+
+fn check_permissions_len(permissions: Option<&Value>) -> Result<()> {
+    let len = permissions.and_then(Value::as_array).map_or(0, Vec::len);
+
+    if len > MAX_PERMISSIONS {
+        return Err(Error::Model(ModelError::TooManyPermissions {
+            max: MAX_PERMISSIONS,
+            got: len,
+        }));
+    }
+
+    Ok(())
+}
+
 /// A builder for creating several [`ApplicationCommandPermission`].
 ///
 /// [`ApplicationCommandPermission`]: crate::model::interactions::ApplicationCommandPermission
@@ -54,7 +72,13 @@ impl CreateApplicationCommandsPermissions {
 
         self
     }
+
+    /// Checks that no command has more permissions than Discord allows.
+    pub(crate) fn check_permissions_len(&self) -> Result<()> {
+        self.0.iter().try_for_each(|command| check_permissions_len(command.get("permissions")))
+    }
 }
+
 /// A builder for creating an [`ApplicationCommandPermission`].
 ///
 /// [`ApplicationCommandPermission`]: crate::model::interactions::ApplicationCommandPermission
@@ -167,6 +191,11 @@ impl CreateApplicationCommandPermissionsData {
 
         self
     }
+
+    /// Checks that there are no more permissions than Discord allows.
+    pub(crate) fn check_permissions_len(&self) -> Result<()> {
+        check_permissions_len(self.0.get("permissions"))
+    }
 }
 
 /// A builder for creating an [`ApplicationCommandPermissionData`].
@@ -201,3 +230,38 @@ impl CreateApplicationCommandPermissionData {
         self
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_too_many_permissions() {
+        let command = |id: u64, len: u64| {
+            let mut command = CreateApplicationCommandPermissions::default();
+            command.id(id);
+
+            for user_id in 0..len {
+                command.create_permission(|p| p.id(user_id).permission(true));
+            }
+
+            command
+        };
+
+        let mut permissions = CreateApplicationCommandsPermissions::default();
+        permissions.add_application_command(command(1, 10));
+
+        assert!(permissions.check_permissions_len().is_ok());
+
+        permissions.add_application_command(command(2, 11));
+
+        assert!(matches!(
+            permissions.check_permissions_len(),
+            Err(Error::Model(ModelError::TooManyPermissions {
+                max: 10,
+                got: 11,
+            }))
+        ));
+    }
+}
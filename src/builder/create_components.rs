@@ -0,0 +1,418 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::model::channel::ReactionType;
+use crate::model::interactions::{ButtonStyle, ComponentType, SelectMenuOption};
+use crate::utils;
+
+/// A builder for the action rows of a message, which hold its components.
+///
+/// **Note**: A message can have up to 5 action rows.
+#[derive(Clone, Debug, Default)]
+pub struct CreateComponents(pub Vec<Value>);
+
+impl CreateComponents {
+    /// Creates an action row.
+    pub fn create_action_row<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateActionRow) -> &mut CreateActionRow,
+    {
+        let mut row = CreateActionRow::default();
+        f(&mut row);
+        self.add_action_row(row)
+    }
+
+    /// Adds an action row.
+    pub fn add_action_row(&mut self, row: CreateActionRow) -> &mut Self {
+        self.0.push(row.build());
+        self
+    }
+
+    /// Sets all the action rows, replacing any existing ones.
+    pub fn set_action_rows(&mut self, rows: Vec<CreateActionRow>) -> &mut Self {
+        self.0 = rows.into_iter().map(CreateActionRow::build).collect();
+        self
+    }
+}
+
+/// A builder for an action row, holding either up to 5 buttons or a single
+/// select menu.
+#[derive(Clone, Debug, Default)]
+pub struct CreateActionRow(pub Vec<Value>);
+
+impl CreateActionRow {
+    /// Creates a button.
+    pub fn create_button<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateButton) -> &mut CreateButton,
+    {
+        let mut button = CreateButton::default();
+        f(&mut button);
+        self.add_button(button)
+    }
+
+    /// Adds a button.
+    pub fn add_button(&mut self, button: CreateButton) -> &mut Self {
+        let map = utils::hashmap_to_json_map(button.0);
+        self.0.push(Value::Object(map));
+        self
+    }
+
+    /// Creates a select menu.
+    ///
+    /// **Note**: A select menu takes up a whole action row.
+    pub fn create_select_menu<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateSelectMenu) -> &mut CreateSelectMenu,
+    {
+        let mut menu = CreateSelectMenu::default();
+        f(&mut menu);
+        self.add_select_menu(menu)
+    }
+
+    /// Adds a select menu.
+    ///
+    /// **Note**: A select menu takes up a whole action row.
+    pub fn add_select_menu(&mut self, menu: CreateSelectMenu) -> &mut Self {
+        let map = utils::hashmap_to_json_map(menu.0);
+        self.0.push(Value::Object(map));
+        self
+    }
+
+    pub(crate) fn build(self) -> Value {
+        json!({
+            "type": ComponentType::ActionRow as u8,
+            "components": self.0,
+        })
+    }
+}
+
+/// A builder for a button.
+///
+/// A button needs either a [`custom_id`], which is sent back in the
+/// interaction when it is clicked, or a [`url`] if it is a
+/// [`ButtonStyle::Link`], but not both.
+///
+/// [`custom_id`]: Self::custom_id
+/// [`url`]: Self::url
+#[derive(Clone, Debug)]
+pub struct CreateButton(pub HashMap<&'static str, Value>);
+
+impl CreateButton {
+    /// Sets the custom Id of the button.
+    ///
+    /// **Note**: It must be at most 100 characters.
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::String(id.to_string()));
+        self
+    }
+
+    /// Sets the text shown on the button.
+    ///
+    /// **Note**: It must be at most 80 characters.
+    pub fn label<D: ToString>(&mut self, label: D) -> &mut Self {
+        self.0.insert("label", Value::String(label.to_string()));
+        self
+    }
+
+    /// Sets the style of the button.
+    ///
+    /// Defaults to [`ButtonStyle::Primary`].
+    pub fn style(&mut self, style: ButtonStyle) -> &mut Self {
+        self.0.insert("style", Value::Number(serde_json::Number::from(style as u8)));
+        self
+    }
+
+    /// Sets the URL opened by a [`ButtonStyle::Link`] button.
+    pub fn url<D: ToString>(&mut self, url: D) -> &mut Self {
+        self.0.insert("url", Value::String(url.to_string()));
+        self
+    }
+
+    /// Sets the emoji shown on the button.
+    pub fn emoji<R: Into<ReactionType>>(&mut self, emoji: R) -> &mut Self {
+        self.0.insert("emoji", emoji_to_value(emoji.into()));
+        self
+    }
+
+    /// Sets whether the button is disabled.
+    ///
+    /// Defaults to `false`.
+    pub fn disabled(&mut self, disabled: bool) -> &mut Self {
+        self.0.insert("disabled", Value::Bool(disabled));
+        self
+    }
+}
+
+impl Default for CreateButton {
+    fn default() -> CreateButton {
+        let mut map = HashMap::new();
+        map.insert("type", Value::Number(serde_json::Number::from(ComponentType::Button as u8)));
+        map.insert("style", Value::Number(serde_json::Number::from(ButtonStyle::Primary as u8)));
+
+        CreateButton(map)
+    }
+}
+
+/// A builder for a select menu, letting users choose between options.
+#[derive(Clone, Debug)]
+pub struct CreateSelectMenu(pub HashMap<&'static str, Value>);
+
+impl CreateSelectMenu {
+    /// Sets the custom Id of the select menu.
+    ///
+    /// **Note**: It must be at most 100 characters.
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::String(id.to_string()));
+        self
+    }
+
+    /// Sets the text shown when no option is selected.
+    ///
+    /// **Note**: It must be at most 150 characters.
+    pub fn placeholder<D: ToString>(&mut self, placeholder: D) -> &mut Self {
+        self.0.insert("placeholder", Value::String(placeholder.to_string()));
+        self
+    }
+
+    /// Sets the minimum number of options which must be selected.
+    ///
+    /// Defaults to 1.
+    pub fn min_values(&mut self, min: u64) -> &mut Self {
+        self.0.insert("min_values", Value::Number(serde_json::Number::from(min)));
+        self
+    }
+
+    /// Sets the maximum number of options which can be selected.
+    ///
+    /// Defaults to 1.
+    ///
+    /// **Note**: It must be at least the number of options selected by
+    /// default, which is checked when the message is sent.
+    pub fn max_values(&mut self, max: u64) -> &mut Self {
+        self.0.insert("max_values", Value::Number(serde_json::Number::from(max)));
+        self
+    }
+
+    /// Sets whether the select menu is disabled.
+    ///
+    /// Defaults to `false`.
+    pub fn disabled(&mut self, disabled: bool) -> &mut Self {
+        self.0.insert("disabled", Value::Bool(disabled));
+        self
+    }
+
+    /// Creates an option of the select menu.
+    ///
+    /// **Note**: A select menu can have up to 25 options.
+    pub fn create_option<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateSelectMenuOption) -> &mut CreateSelectMenuOption,
+    {
+        let mut option = CreateSelectMenuOption::default();
+        f(&mut option);
+        self.add_option(option)
+    }
+
+    /// Adds an option to the select menu.
+    ///
+    /// **Note**: A select menu can have up to 25 options.
+    pub fn add_option(&mut self, option: CreateSelectMenuOption) -> &mut Self {
+        let map = utils::hashmap_to_json_map(option.0);
+        let options = self.0.entry("options").or_insert_with(|| Value::Array(vec![]));
+
+        if let Some(options) = options.as_array_mut() {
+            options.push(Value::Object(map));
+        }
+
+        self
+    }
+
+}
+
+impl Default for CreateSelectMenu {
+    fn default() -> CreateSelectMenu {
+        let mut map = HashMap::new();
+        let kind = ComponentType::SelectMenu as u8;
+        map.insert("type", Value::Number(serde_json::Number::from(kind)));
+
+        CreateSelectMenu(map)
+    }
+}
+
+/// A builder for an option of a [`CreateSelectMenu`].
+///
+/// [`Self::label`] and [`Self::value`] are required fields.
+#[derive(Clone, Debug, Default)]
+pub struct CreateSelectMenuOption(pub HashMap<&'static str, Value>);
+
+impl CreateSelectMenuOption {
+    /// Sets the text shown for the option.
+    ///
+    /// **Note**: It must be at most 100 characters.
+    pub fn label<D: ToString>(&mut self, label: D) -> &mut Self {
+        self.0.insert("label", Value::String(label.to_string()));
+        self
+    }
+
+    /// Sets the value sent in the interaction when the option is selected.
+    ///
+    /// **Note**: It must be at most 100 characters.
+    pub fn value<D: ToString>(&mut self, value: D) -> &mut Self {
+        self.0.insert("value", Value::String(value.to_string()));
+        self
+    }
+
+    /// Sets the text shown below the label of the option.
+    ///
+    /// **Note**: It must be at most 100 characters.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Sets the emoji shown next to the option.
+    pub fn emoji<R: Into<ReactionType>>(&mut self, emoji: R) -> &mut Self {
+        self.0.insert("emoji", emoji_to_value(emoji.into()));
+        self
+    }
+
+    /// Sets whether the option is selected when the message is first shown.
+    ///
+    /// Defaults to `false`.
+    pub fn default_selected(&mut self, selected: bool) -> &mut Self {
+        self.0.insert("default", Value::Bool(selected));
+        self
+    }
+}
+
+impl From<SelectMenuOption> for CreateSelectMenuOption {
+    fn from(option: SelectMenuOption) -> Self {
+        let mut builder = CreateSelectMenuOption::default();
+        builder.label(option.label).value(option.value).default_selected(option.default);
+
+        if let Some(description) = option.description {
+            builder.description(description);
+        }
+
+        if let Some(emoji) = option.emoji {
+            builder.emoji(emoji);
+        }
+
+        builder
+    }
+}
+
+fn emoji_to_value(emoji: ReactionType) -> Value {
+    match emoji {
+        ReactionType::Custom {
+            animated,
+            id,
+            name,
+        } => json!({
+            "animated": animated,
+            "id": id.0.to_string(),
+            "name": name,
+        }),
+        ReactionType::Unicode(name) => json!({
+            "name": name,
+        }),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use super::*;
+    use crate::internal::prelude::*;
+    use crate::model::channel::Message;
+    use crate::model::ModelError;
+
+    #[test]
+    fn test_action_row() {
+        let mut components = CreateComponents::default();
+        components.create_action_row(|row| {
+            row.create_button(|b| b.custom_id("yes").label("Yes").style(ButtonStyle::Success))
+                .create_button(|b| {
+                    b.url("https://example.com").emoji('\u{2705}').style(ButtonStyle::Link)
+                })
+        });
+
+        assert_eq!(
+            components.0,
+            vec![json!({
+                "type": 1,
+                "components": [
+                    {"type": 2, "style": 3, "custom_id": "yes", "label": "Yes"},
+                    {
+                        "type": 2,
+                        "style": 5,
+                        "url": "https://example.com",
+                        "emoji": {"name": "\u{2705}"},
+                    },
+                ],
+            })]
+        );
+    }
+
+    #[test]
+    fn test_select_menu() {
+        let mut row = CreateActionRow::default();
+        row.create_select_menu(|m| {
+            m.custom_id("colour")
+                .max_values(2)
+                .create_option(|o| o.label("Red").value("red").default_selected(true))
+                .create_option(|o| o.label("Blue").value("blue").default_selected(true))
+        });
+
+        assert_eq!(row.0.len(), 1);
+        assert_eq!(row.0[0]["type"], 3);
+        assert_eq!(row.0[0]["max_values"], 2);
+        assert_eq!(row.0[0]["options"][1]["value"], "blue");
+    }
+
+    #[test]
+    fn test_select_menu_default_options() {
+        let components = |max_values: u64| {
+            let mut components = CreateComponents::default();
+            components.create_action_row(|row| {
+                row.create_select_menu(|m| {
+                    m.custom_id("colour")
+                        .max_values(max_values)
+                        .create_option(|o| o.label("Red").value("red").default_selected(true))
+                        .create_option(|o| o.label("Blue").value("blue").default_selected(true))
+                })
+            });
+
+            let mut map = JsonMap::new();
+            map.insert("components".to_string(), Value::Array(components.0));
+            map
+        };
+
+        assert!(Message::check_components(&components(2)).is_ok());
+        assert!(matches!(
+            Message::check_components(&components(1)),
+            Err(Error::Model(ModelError::TooManyDefaultOptions {
+                max: 1,
+                got: 2,
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_select_menu_option_from_model() {
+        let option: SelectMenuOption = serde_json::from_value(json!({
+            "label": "Red",
+            "value": "red",
+            "default": true,
+        }))
+        .unwrap();
+
+        let builder = CreateSelectMenuOption::from(option);
+
+        assert_eq!(builder.0["label"], "Red");
+        assert_eq!(builder.0["default"], true);
+        assert!(!builder.0.contains_key("description"));
+    }
+}
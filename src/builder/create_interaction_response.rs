@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
-use serde_json::Value;
+use serde_json::{Map, Value};
 
-use super::{CreateAllowedMentions, CreateEmbed};
+use super::{CreateAllowedMentions, CreateComponents, CreateEmbed, CreateModal};
 use crate::{
     model::interactions::{
         InteractionApplicationCommandCallbackDataFlags,
@@ -36,6 +36,134 @@ impl CreateInteractionResponse {
         self.0.insert("data", data);
         self
     }
+
+    /// Responds with a modal, setting the [`kind`] to
+    /// [`InteractionResponseType::Modal`].
+    ///
+    /// **Note**: Modals cannot be sent in response to [`ModalSubmit`] or
+    /// [`Ping`] interactions.
+    ///
+    /// [`kind`]: Self::kind
+    /// [`ModalSubmit`]: crate::model::interactions::InteractionType::ModalSubmit
+    /// [`Ping`]: crate::model::interactions::InteractionType::Ping
+    pub fn modal<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateModal) -> &mut CreateModal,
+    {
+        let mut modal = CreateModal::default();
+        f(&mut modal);
+        let map = utils::hashmap_to_json_map(modal.0);
+
+        self.kind(InteractionResponseType::Modal);
+        self.0.insert("data", Value::Object(map));
+        self
+    }
+
+    /// Creates an embed for the response message.
+    ///
+    /// This is a shorthand for calling [`CreateInteractionResponseData::embed`]
+    /// inside of [`Self::interaction_response_data`].
+    ///
+    /// **Note**: Calling [`Self::interaction_response_data`] afterwards will
+    /// overwrite any embeds set through this method.
+    pub fn embed<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed,
+    {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+        self.set_embed(embed)
+    }
+
+    /// Adds an embed to the response message.
+    ///
+    /// **Note**: Calling [`Self::interaction_response_data`] afterwards will
+    /// overwrite any embeds set through this method.
+    pub fn set_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        let map = utils::hashmap_to_json_map(embed.0);
+        let embed = Value::Object(map);
+
+        let data = self.0.entry("data").or_insert_with(|| Value::Object(Map::new()));
+
+        if let Some(data) = data.as_object_mut() {
+            let embeds = data.entry("embeds").or_insert_with(|| Value::Array(vec![]));
+
+            if let Some(embeds) = embeds.as_array_mut() {
+                embeds.push(embed);
+            }
+        }
+
+        self
+    }
+
+    /// Sets the embeds of the response message, replacing any existing ones.
+    ///
+    /// **Note**: You can only have up to 10 embeds per message.
+    pub fn embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
+        if let Some(data) = self.0.get_mut("data").and_then(Value::as_object_mut) {
+            data.remove("embeds");
+        }
+
+        for embed in embeds {
+            self.set_embed(embed);
+        }
+
+        self
+    }
+
+    /// Adds action rows of components to the response message.
+    ///
+    /// This is a shorthand for calling
+    /// [`CreateInteractionResponseData::components`] inside of
+    /// [`Self::interaction_response_data`].
+    ///
+    /// **Note**: Calling [`Self::interaction_response_data`] afterwards will
+    /// overwrite any components set through this method.
+    pub fn add_components<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateComponents) -> &mut CreateComponents,
+    {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        let data = self.0.entry("data").or_insert_with(|| Value::Object(Map::new()));
+
+        if let Some(data) = data.as_object_mut() {
+            let rows = data.entry("components").or_insert_with(|| Value::Array(vec![]));
+
+            if let Some(rows) = rows.as_array_mut() {
+                rows.extend(components.0);
+            }
+        }
+
+        self
+    }
+
+    /// Builds the JSON payload of the response, as it would be sent to
+    /// Discord, without sending it.
+    ///
+    /// This is useful to log the response or to compare it against an
+    /// expected payload in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    /// use serenity::builder::CreateInteractionResponse;
+    ///
+    /// let mut response = CreateInteractionResponse::default();
+    /// response.interaction_response_data(|d| d.content("Pong!"));
+    ///
+    /// let payload = response.build_json().expect("Valid JSON");
+    /// assert_eq!(payload, json!({"type": 4, "data": {"content": "Pong!"}}));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`serde_json::Error`] if the payload cannot be serialized.
+    pub fn build_json(&self) -> serde_json::Result<Value> {
+        serde_json::to_value(&self.0)
+    }
 }
 
 impl<'a> Default for CreateInteractionResponse {
@@ -112,6 +240,18 @@ impl CreateInteractionResponseData {
         self
     }
 
+    /// Sets the components of the message, replacing any existing ones.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateComponents) -> &mut CreateComponents,
+    {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+
     /// Sets the flags for the message.
     pub fn flags(&mut self, flags: InteractionApplicationCommandCallbackDataFlags) -> &mut Self {
         self.0.insert("flags", Value::Number(serde_json::Number::from(flags.bits())));
@@ -2,7 +2,8 @@ use std::collections::HashMap;
 
 use serde_json::Value;
 
-use super::{CreateAllowedMentions, CreateEmbed};
+use super::{CreateAllowedMentions, CreateComponents, CreateEmbed};
+use crate::model::interactions::InteractionApplicationCommandCallbackDataFlags;
 use crate::{http::AttachmentType, utils};
 
 #[derive(Clone, Debug, Default)]
@@ -46,11 +47,18 @@ impl<'a> CreateInteractionResponseFollowup<'a> {
         self.0.insert("avatar_url", Value::String(avatar_url));
         self
     }
+
     /// Set whether the message is text-to-speech.
     ///
     /// Think carefully before setting this to `true`.
     ///
+    /// **Note**: This follows the same channel permission rules as regular
+    /// text-to-speech messages, which require the [Send TTS Messages]
+    /// permission.
+    ///
     /// Defaults to `false`.
+    ///
+    /// [Send TTS Messages]: crate::model::permissions::Permissions::SEND_TTS_MESSAGES
     pub fn tts(&mut self, tts: bool) -> &mut Self {
         self.0.insert("tts", Value::Bool(tts));
         self
@@ -115,4 +123,62 @@ impl<'a> CreateInteractionResponseFollowup<'a> {
         self.0.insert("allowed_mentions", allowed_mentions);
         self
     }
+
+    /// Sets the flags for the message.
+    ///
+    /// This replaces any flags set before, such as with
+    /// [`Self::suppress_embeds`].
+    pub fn flags(&mut self, flags: InteractionApplicationCommandCallbackDataFlags) -> &mut Self {
+        self.0.insert("flags", Value::Number(serde_json::Number::from(flags.bits())));
+        self
+    }
+
+    /// Sets whether the message should not include any embeds for the links
+    /// in its content.
+    ///
+    /// Other flags set with [`Self::flags`] are kept as is.
+    ///
+    /// Defaults to `false`.
+    pub fn suppress_embeds(&mut self, suppress: bool) -> &mut Self {
+        let bits = self.0.get("flags").and_then(Value::as_u64).unwrap_or_default();
+        let mut flags = InteractionApplicationCommandCallbackDataFlags::from_bits_truncate(bits);
+        flags.set(InteractionApplicationCommandCallbackDataFlags::SUPPRESS_EMBEDS, suppress);
+
+        self.flags(flags)
+    }
+
+    /// Adds action rows of components to the message.
+    pub fn add_components<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateComponents) -> &mut CreateComponents,
+    {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        let rows = self.0.entry("components").or_insert_with(|| Value::Array(vec![]));
+
+        if let Some(rows) = rows.as_array_mut() {
+            rows.extend(components.0);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_suppress_embeds() {
+        let mut followup = CreateInteractionResponseFollowup::default();
+        followup.flags(InteractionApplicationCommandCallbackDataFlags::EPHEMERAL);
+        followup.suppress_embeds(true);
+
+        assert_eq!(followup.0["flags"], Value::from(64 | 4));
+
+        followup.suppress_embeds(false);
+
+        assert_eq!(followup.0["flags"], Value::from(64));
+    }
 }
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::model::interactions::{ComponentType, TextInputStyle};
+use crate::utils;
+
+/// A builder for a modal, a form shown to the user in a popup.
+///
+/// [`Self::custom_id`] and [`Self::title`] are required fields, and the modal
+/// needs at least one text input.
+#[derive(Clone, Debug, Default)]
+pub struct CreateModal(pub HashMap<&'static str, Value>);
+
+impl CreateModal {
+    /// Sets the custom Id of the modal, which is sent back in the
+    /// [`ModalSubmit`] interaction.
+    ///
+    /// **Note**: It must be at most 100 characters.
+    ///
+    /// [`ModalSubmit`]: crate::model::interactions::InteractionType::ModalSubmit
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::String(id.to_string()));
+        self
+    }
+
+    /// Sets the title shown at the top of the modal.
+    ///
+    /// **Note**: It must be at most 45 characters.
+    pub fn title<D: ToString>(&mut self, title: D) -> &mut Self {
+        self.0.insert("title", Value::String(title.to_string()));
+        self
+    }
+
+    /// Creates a text input, in its own action row.
+    ///
+    /// **Note**: A modal can have up to 5 text inputs.
+    pub fn create_text_input<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateTextInput) -> &mut CreateTextInput,
+    {
+        let mut input = CreateTextInput::default();
+        f(&mut input);
+        self.add_text_input(input)
+    }
+
+    /// Adds a text input, in its own action row.
+    ///
+    /// **Note**: A modal can have up to 5 text inputs.
+    pub fn add_text_input(&mut self, input: CreateTextInput) -> &mut Self {
+        let row = json!({
+            "type": ComponentType::ActionRow as u8,
+            "components": [Value::Object(utils::hashmap_to_json_map(input.0))],
+        });
+
+        let rows = self.0.entry("components").or_insert_with(|| Value::Array(vec![]));
+
+        if let Some(rows) = rows.as_array_mut() {
+            rows.push(row);
+        }
+
+        self
+    }
+}
+
+/// A builder for a text input of a [`CreateModal`].
+///
+/// [`Self::custom_id`] and [`Self::label`] are required fields.
+#[derive(Clone, Debug)]
+pub struct CreateTextInput(pub HashMap<&'static str, Value>);
+
+impl CreateTextInput {
+    /// Sets the custom Id of the text input, which its value is keyed by in
+    /// the submitted modal.
+    ///
+    /// **Note**: It must be at most 100 characters.
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::String(id.to_string()));
+        self
+    }
+
+    /// Sets the label shown above the text input.
+    ///
+    /// **Note**: It must be at most 45 characters.
+    pub fn label<D: ToString>(&mut self, label: D) -> &mut Self {
+        self.0.insert("label", Value::String(label.to_string()));
+        self
+    }
+
+    /// Sets the style of the text input.
+    ///
+    /// Defaults to [`TextInputStyle::Short`].
+    pub fn style(&mut self, style: TextInputStyle) -> &mut Self {
+        self.0.insert("style", Value::Number(serde_json::Number::from(style as u8)));
+        self
+    }
+
+    /// Sets the minimum length of the value, between 0 and 4000.
+    pub fn min_length(&mut self, min: u64) -> &mut Self {
+        self.0.insert("min_length", Value::Number(serde_json::Number::from(min)));
+        self
+    }
+
+    /// Sets the maximum length of the value, between 1 and 4000.
+    pub fn max_length(&mut self, max: u64) -> &mut Self {
+        self.0.insert("max_length", Value::Number(serde_json::Number::from(max)));
+        self
+    }
+
+    /// Sets whether a value must be entered.
+    ///
+    /// Defaults to `true`.
+    pub fn required(&mut self, required: bool) -> &mut Self {
+        self.0.insert("required", Value::Bool(required));
+        self
+    }
+
+    /// Sets the value the text input is pre-filled with.
+    pub fn value<D: ToString>(&mut self, value: D) -> &mut Self {
+        self.0.insert("value", Value::String(value.to_string()));
+        self
+    }
+
+    /// Sets the text shown when the text input is empty.
+    ///
+    /// **Note**: It must be at most 100 characters.
+    pub fn placeholder<D: ToString>(&mut self, placeholder: D) -> &mut Self {
+        self.0.insert("placeholder", Value::String(placeholder.to_string()));
+        self
+    }
+}
+
+impl Default for CreateTextInput {
+    fn default() -> CreateTextInput {
+        let mut map = HashMap::new();
+        let kind = ComponentType::InputText as u8;
+        map.insert("type", Value::Number(serde_json::Number::from(kind)));
+        map.insert("style", Value::Number(serde_json::Number::from(TextInputStyle::Short as u8)));
+
+        CreateTextInput(map)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use super::*;
+    use crate::builder::CreateInteractionResponse;
+
+    #[test]
+    fn test_modal_response() {
+        let mut response = CreateInteractionResponse::default();
+        response.modal(|m| {
+            m.custom_id("feedback").title("Feedback").create_text_input(|i| {
+                i.custom_id("comment").label("Comment").style(TextInputStyle::Paragraph)
+            })
+        });
+
+        assert_eq!(
+            response.build_json().unwrap(),
+            json!({
+                "type": 9,
+                "data": {
+                    "custom_id": "feedback",
+                    "title": "Feedback",
+                    "components": [{
+                        "type": 1,
+                        "components": [{
+                            "type": 4,
+                            "style": 2,
+                            "custom_id": "comment",
+                            "label": "Comment",
+                        }],
+                    }],
+                },
+            })
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use super::{CreateEmbed, EditInteractionResponse};
+
+/// The content and embeds used to edit a deferred interaction response.
+///
+/// This is returned by the work done in [`Interaction::respond_deferred_then`].
+///
+/// [`Interaction::respond_deferred_then`]: crate::model::interactions::Interaction::respond_deferred_then
+#[derive(Clone, Debug, Default)]
+pub struct CreateResponse {
+    /// The content of the message.
+    pub content: Option<String>,
+    /// The embeds of the message.
+    pub embeds: Vec<CreateEmbed>,
+}
+
+impl CreateResponse {
+    /// Set the content of the message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    #[inline]
+    pub fn content<D: ToString>(&mut self, content: D) -> &mut Self {
+        self.content = Some(content.to_string());
+        self
+    }
+
+    /// Creates an embed for the message.
+    pub fn create_embed<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed,
+    {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+        self.add_embed(embed)
+    }
+
+    /// Adds an embed for the message.
+    ///
+    /// **Note**: You can only have up to 10 embeds per message.
+    pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        self.embeds.push(embed);
+        self
+    }
+
+    pub(crate) fn apply(self, edit: &mut EditInteractionResponse) -> &mut EditInteractionResponse {
+        if let Some(content) = self.content {
+            edit.content(content);
+        }
+
+        edit.set_embeds(self.embeds)
+    }
+}
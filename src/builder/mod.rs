@@ -14,6 +14,9 @@ mod create_application_command;
 #[cfg(feature = "unstable_discord_api")]
 #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
 mod create_application_command_permission;
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+mod create_components;
 
 mod create_allowed_mentions;
 #[cfg(feature = "unstable_discord_api")]
@@ -24,6 +27,12 @@ mod create_interaction_response;
 mod create_interaction_response_followup;
 mod create_invite;
 mod create_message;
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+mod create_modal;
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+mod create_response;
 mod edit_channel;
 mod edit_guild;
 #[cfg(feature = "unstable_discord_api")]
@@ -69,7 +78,16 @@ pub use self::{
         CreateApplicationCommandPermissionsData,
         CreateApplicationCommandsPermissions,
     },
+    create_components::{
+        CreateActionRow,
+        CreateButton,
+        CreateComponents,
+        CreateSelectMenu,
+        CreateSelectMenuOption,
+    },
     create_interaction_response::{CreateInteractionResponse, CreateInteractionResponseData},
     create_interaction_response_followup::CreateInteractionResponseFollowup,
+    create_modal::{CreateModal, CreateTextInput},
+    create_response::CreateResponse,
     edit_interaction_response::EditInteractionResponse,
 };
@@ -118,6 +118,16 @@ __impl_bitflags! {
         ///
         /// - TYPING_START
         DIRECT_MESSAGE_TYPING = 1 << 14;
+        /// Enable following gateway events:
+        ///
+        /// - MESSAGE_POLL_VOTE_ADD
+        /// - MESSAGE_POLL_VOTE_REMOVE
+        GUILD_MESSAGE_POLLS = 1 << 24;
+        /// Enable following gateway events:
+        ///
+        /// - MESSAGE_POLL_VOTE_ADD
+        /// - MESSAGE_POLL_VOTE_REMOVE
+        DIRECT_MESSAGE_POLLS = 1 << 25;
     }
 }
 
@@ -278,4 +288,20 @@ impl GatewayIntents {
     pub fn direct_message_typing(self) -> bool {
         self.contains(Self::DIRECT_MESSAGE_TYPING)
     }
+
+    /// Shorthand for checking that the set of intents contains the
+    /// [GUILD_MESSAGE_POLLS] intent.
+    ///
+    /// [GUILD_MESSAGE_POLLS]: Self::GUILD_MESSAGE_POLLS
+    pub fn guild_message_polls(self) -> bool {
+        self.contains(Self::GUILD_MESSAGE_POLLS)
+    }
+
+    /// Shorthand for checking that the set of intents contains the
+    /// [DIRECT_MESSAGE_POLLS] intent.
+    ///
+    /// [DIRECT_MESSAGE_POLLS]: Self::DIRECT_MESSAGE_POLLS
+    pub fn direct_message_polls(self) -> bool {
+        self.contains(Self::DIRECT_MESSAGE_POLLS)
+    }
 }
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
 use futures::channel::mpsc::UnboundedSender as Sender;
+#[cfg(feature = "unstable_discord_api")]
+use tokio::sync::broadcast;
 use tokio::sync::RwLock;
 use typemap_rev::TypeMap;
 
@@ -43,6 +45,8 @@ pub struct Context {
     pub http: Arc<Http>,
     #[cfg(feature = "cache")]
     pub cache: Arc<Cache>,
+    #[cfg(feature = "unstable_discord_api")]
+    pub(crate) interaction_broadcast: Option<broadcast::Sender<Arc<Interaction>>>,
 }
 
 impl Context {
@@ -61,6 +65,8 @@ impl Context {
             data,
             http,
             cache,
+            #[cfg(feature = "unstable_discord_api")]
+            interaction_broadcast: None,
         }
     }
 
@@ -70,6 +76,8 @@ impl Context {
             shard_id,
             data,
             http,
+            #[cfg(feature = "unstable_discord_api")]
+            interaction_broadcast: None,
         }
     }
 
@@ -86,6 +94,62 @@ impl Context {
             shard_id,
             data,
             http,
+            #[cfg(feature = "unstable_discord_api")]
+            interaction_broadcast: None,
+        }
+    }
+
+    /// Subscribes to every [`Interaction`] received by the client, on all
+    /// shards.
+    ///
+    /// Each interaction is sent to the receivers before
+    /// [`EventHandler::interaction_create`] is dispatched, allowing multiple
+    /// independent consumers, such as a logging system and the command handler,
+    /// to handle the same interactions.
+    ///
+    /// The receiver only gets interactions received after it was created, so
+    /// it is best to subscribe once, in [`EventHandler::ready`]. If a receiver
+    /// falls behind by more than the capacity set with
+    /// [`ClientBuilder::interaction_broadcast_capacity`], the oldest
+    /// interactions are skipped.
+    ///
+    /// **Note**: If the context was not created by a [`Client`], the returned
+    /// receiver is closed and never receives an interaction.
+    ///
+    /// # Examples
+    ///
+    /// Log every interaction while the event handler responds to them:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::gateway::Ready;
+    /// #
+    /// struct Handler;
+    ///
+    /// #[serenity::async_trait]
+    /// impl EventHandler for Handler {
+    ///     async fn ready(&self, ctx: Context, _: Ready) {
+    ///         let mut interactions = ctx.interaction_broadcast();
+    ///
+    ///         tokio::spawn(async move {
+    ///             while let Ok(interaction) = interactions.recv().await {
+    ///                 println!("Received interaction {}", interaction.id);
+    ///             }
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`EventHandler::interaction_create`]: crate::client::EventHandler::interaction_create
+    /// [`EventHandler::ready`]: crate::client::EventHandler::ready
+    /// [`ClientBuilder::interaction_broadcast_capacity`]: crate::client::ClientBuilder::interaction_broadcast_capacity
+    /// [`Client`]: crate::client::Client
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub fn interaction_broadcast(&self) -> broadcast::Receiver<Arc<Interaction>> {
+        match &self.interaction_broadcast {
+            Some(sender) => sender.subscribe(),
+            None => broadcast::channel(1).1,
         }
     }
 
@@ -17,11 +17,10 @@ use super::{
     event_handler::{EventHandler, RawEventHandler},
 };
 #[cfg(feature = "cache")]
-use crate::cache::{Cache, CacheUpdate};
+use crate::cache::CacheUpdate;
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
 use crate::gateway::InterMessage;
-use crate::http::Http;
 #[cfg(feature = "cache")]
 use crate::model::id::GuildId;
 use crate::model::{
@@ -46,25 +45,35 @@ async fn update<E>(_cache_and_http: &Arc<CacheAndHttp>, _event: &mut E) -> Optio
     None
 }
 
-#[cfg(feature = "cache")]
 fn context(
     data: &Arc<RwLock<TypeMap>>,
     runner_tx: &Sender<InterMessage>,
     shard_id: u64,
-    http: &Arc<Http>,
-    cache: &Arc<Cache>,
-) -> Context {
-    Context::new(Arc::clone(data), runner_tx.clone(), shard_id, Arc::clone(http), Arc::clone(cache))
-}
-
-#[cfg(not(feature = "cache"))]
-fn context(
-    data: &Arc<RwLock<TypeMap>>,
-    runner_tx: &Sender<InterMessage>,
-    shard_id: u64,
-    http: &Arc<Http>,
+    cache_and_http: &Arc<CacheAndHttp>,
 ) -> Context {
-    Context::new(Arc::clone(data), runner_tx.clone(), shard_id, Arc::clone(http))
+    #[cfg(feature = "cache")]
+    let context = Context::new(
+        Arc::clone(data),
+        runner_tx.clone(),
+        shard_id,
+        Arc::clone(&cache_and_http.http),
+        Arc::clone(&cache_and_http.cache),
+    );
+    #[cfg(not(feature = "cache"))]
+    let context = Context::new(
+        Arc::clone(data),
+        runner_tx.clone(),
+        shard_id,
+        Arc::clone(&cache_and_http.http),
+    );
+
+    #[cfg(feature = "unstable_discord_api")]
+    let context = Context {
+        interaction_broadcast: cache_and_http.interaction_broadcast.clone(),
+        ..context
+    };
+
+    context
 }
 
 // Once we can use `Box` as part of a pattern, we will reconsider boxing.
@@ -159,6 +168,9 @@ pub(crate) fn dispatch<'rec>(
     cache_and_http: Arc<CacheAndHttp>,
 ) -> BoxFuture<'rec, ()> {
     async move {
+        #[cfg(feature = "unstable_discord_api")]
+        broadcast_interaction(&event, &cache_and_http);
+
         match (event_handler, raw_event_handler) {
             (None, None) => {
                 event.update(&cache_and_http).await;
@@ -166,16 +178,7 @@ pub(crate) fn dispatch<'rec>(
                 if let DispatchEvent::Model(Event::MessageCreate(event)) = event {
                     #[cfg(feature = "framework")]
                     {
-                        #[cfg(not(feature = "cache"))]
-                        let context = context(data, runner_tx, shard_id, &cache_and_http.http);
-                        #[cfg(feature = "cache")]
-                        let context = context(
-                            data,
-                            runner_tx,
-                            shard_id,
-                            &cache_and_http.http,
-                            &cache_and_http.cache,
-                        );
+                        let context = context(data, runner_tx, shard_id, &cache_and_http);
 
                         let framework = Arc::clone(&framework);
 
@@ -189,16 +192,7 @@ pub(crate) fn dispatch<'rec>(
                 DispatchEvent::Model(Event::MessageCreate(mut event)) => {
                     update(&cache_and_http, &mut event).await;
 
-                    #[cfg(not(feature = "cache"))]
-                    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
-                    #[cfg(feature = "cache")]
-                    let context = context(
-                        data,
-                        runner_tx,
-                        shard_id,
-                        &cache_and_http.http,
-                        &cache_and_http.cache,
-                    );
+                    let context = context(data, runner_tx, shard_id, &cache_and_http);
 
                     #[cfg(not(feature = "framework"))]
                     {
@@ -227,16 +221,7 @@ pub(crate) fn dispatch<'rec>(
                 if let DispatchEvent::Model(event) = event {
                     let event_handler = Arc::clone(rh);
 
-                    #[cfg(not(feature = "cache"))]
-                    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
-                    #[cfg(feature = "cache")]
-                    let context = context(
-                        data,
-                        runner_tx,
-                        shard_id,
-                        &cache_and_http.http,
-                        &cache_and_http.cache,
-                    );
+                    let context = context(data, runner_tx, shard_id, &cache_and_http);
 
                     #[cfg(not(feature = "framework"))]
                     {
@@ -266,11 +251,7 @@ pub(crate) fn dispatch<'rec>(
             // We call this function again, passing `None` for each event handler
             // and passing no framework, as we dispatch once we are done right here.
             (Some(ref handler), Some(ref raw_handler)) => {
-                #[cfg(not(feature = "cache"))]
-                let context = context(data, runner_tx, shard_id, &cache_and_http.http);
-                #[cfg(feature = "cache")]
-                let context =
-                    context(data, runner_tx, shard_id, &cache_and_http.http, &cache_and_http.cache);
+                let context = context(data, runner_tx, shard_id, &cache_and_http);
 
                 if let DispatchEvent::Model(ref event) = event {
                     raw_handler.raw_event(context.clone(), event.clone()).await;
@@ -306,6 +287,21 @@ pub(crate) fn dispatch<'rec>(
     .boxed()
 }
 
+/// Sends a clone of an interaction to the receivers of
+/// [`Context::interaction_broadcast`], if there are any.
+#[cfg(feature = "unstable_discord_api")]
+fn broadcast_interaction(event: &DispatchEvent, cache_and_http: &CacheAndHttp) {
+    if let DispatchEvent::Model(Event::InteractionCreate(event)) = event {
+        if let Some(sender) = &cache_and_http.interaction_broadcast {
+            if sender.receiver_count() > 0 {
+                // Receivers may be dropped between the check and the send, in
+                // which case the interaction is simply not broadcast.
+                sender.send(Arc::new(event.interaction.clone())).ok();
+            }
+        }
+    }
+}
+
 async fn dispatch_message(
     context: Context,
     mut message: Message,
@@ -333,10 +329,7 @@ async fn handle_event(
     shard_id: u64,
     cache_and_http: Arc<CacheAndHttp>,
 ) {
-    #[cfg(not(feature = "cache"))]
-    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
-    #[cfg(feature = "cache")]
-    let context = context(data, runner_tx, shard_id, &cache_and_http.http, &cache_and_http.cache);
+    let context = context(data, runner_tx, shard_id, &cache_and_http);
 
     match event {
         DispatchEvent::Client(ClientEvent::ShardStageUpdate(event)) => {
@@ -786,5 +779,45 @@ async fn handle_event(
                     .await;
             });
         },
+        #[cfg(feature = "unstable_discord_api")]
+        DispatchEvent::Model(Event::EntitlementCreate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            tokio::spawn(async move {
+                event_handler.entitlement_create(context, event.entitlement).await;
+            });
+        },
+        #[cfg(feature = "unstable_discord_api")]
+        DispatchEvent::Model(Event::EntitlementUpdate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            tokio::spawn(async move {
+                event_handler.entitlement_update(context, event.entitlement).await;
+            });
+        },
+        #[cfg(feature = "unstable_discord_api")]
+        DispatchEvent::Model(Event::EntitlementDelete(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            tokio::spawn(async move {
+                event_handler.entitlement_delete(context, event.entitlement).await;
+            });
+        },
+        #[cfg(feature = "unstable_discord_api")]
+        DispatchEvent::Model(Event::PollVoteAdd(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            tokio::spawn(async move {
+                event_handler.poll_vote_add(context, event.vote).await;
+            });
+        },
+        #[cfg(feature = "unstable_discord_api")]
+        DispatchEvent::Model(Event::PollVoteRemove(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            tokio::spawn(async move {
+                event_handler.poll_vote_remove(context, event.vote).await;
+            });
+        },
     }
 }
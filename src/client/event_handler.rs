@@ -468,6 +468,41 @@ pub trait EventHandler: Send + Sync {
         _application_id: Option<ApplicationId>,
     ) {
     }
+
+    /// Dispatched when an entitlement is created, such as when a user subscribes to a SKU.
+    ///
+    /// Provides the created entitlement.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    async fn entitlement_create(&self, _ctx: Context, _entitlement: Entitlement) {}
+
+    /// Dispatched when an entitlement is updated, such as when a subscription renews.
+    ///
+    /// Provides the updated entitlement.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    async fn entitlement_update(&self, _ctx: Context, _entitlement: Entitlement) {}
+
+    /// Dispatched when an entitlement is deleted, such as when it is refunded.
+    ///
+    /// Provides the deleted entitlement.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    async fn entitlement_delete(&self, _ctx: Context, _entitlement: Entitlement) {}
+
+    /// Dispatched when a user votes for an answer of a poll.
+    ///
+    /// Provides the added vote.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    async fn poll_vote_add(&self, _ctx: Context, _vote: PollAnswerVote) {}
+
+    /// Dispatched when a user removes their vote for an answer of a poll.
+    ///
+    /// Provides the removed vote.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    async fn poll_vote_remove(&self, _ctx: Context, _vote: PollAnswerVote) {}
 }
 
 /// This core trait for handling raw events
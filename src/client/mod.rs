@@ -38,6 +38,8 @@ use std::{
 };
 
 use futures::future::BoxFuture;
+#[cfg(feature = "unstable_discord_api")]
+use tokio::sync::broadcast;
 use tokio::sync::{Mutex, RwLock};
 use tracing::{debug, error, info, instrument};
 use typemap_rev::{TypeMap, TypeMapKey};
@@ -83,6 +85,8 @@ pub struct ClientBuilder<'a> {
     intents: GatewayIntents,
     #[cfg(feature = "unstable_discord_api")]
     application_id: Option<ApplicationId>,
+    #[cfg(feature = "unstable_discord_api")]
+    interaction_broadcast_capacity: usize,
     #[cfg(feature = "cache")]
     timeout: Option<Duration>,
     #[cfg(feature = "framework")]
@@ -104,6 +108,8 @@ impl<'a> ClientBuilder<'a> {
             intents: GatewayIntents::non_privileged(),
             #[cfg(feature = "unstable_discord_api")]
             application_id: None,
+            #[cfg(feature = "unstable_discord_api")]
+            interaction_broadcast_capacity: 16,
             #[cfg(feature = "cache")]
             timeout: None,
             #[cfg(feature = "framework")]
@@ -193,6 +199,19 @@ impl<'a> ClientBuilder<'a> {
         self
     }
 
+    /// Sets how many interactions the receivers of
+    /// [`Context::interaction_broadcast`] may fall behind by before the oldest
+    /// ones are skipped.
+    ///
+    /// By default, the capacity is 16. A capacity of 0 is raised to 1.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub fn interaction_broadcast_capacity(mut self, capacity: usize) -> Self {
+        self.interaction_broadcast_capacity = capacity.max(1);
+
+        self
+    }
+
     /// Sets how long - if wanted to begin with - a cache update shall
     /// be attempted for. After the `timeout` ran out, the update will be
     /// skipped.
@@ -332,6 +351,10 @@ impl<'a> Future for ClientBuilder<'a> {
                 #[cfg(feature = "cache")]
                 update_cache_timeout: self.timeout.take(),
                 http: Arc::clone(&http),
+                #[cfg(feature = "unstable_discord_api")]
+                interaction_broadcast: Some(
+                    broadcast::channel(self.interaction_broadcast_capacity).0,
+                ),
             });
 
             self.fut = Some(Box::pin(async move {
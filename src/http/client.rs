@@ -8,6 +8,8 @@ use std::{
     sync::Arc,
     task::{Context as FutContext, Poll},
 };
+#[cfg(feature = "unstable_discord_api")]
+use std::time::{Duration, Instant};
 
 use bytes::buf::Buf;
 use futures::future::BoxFuture;
@@ -21,6 +23,8 @@ use reqwest::{multipart::Part, Client, ClientBuilder, Response as ReqwestRespons
 use serde::de::DeserializeOwned;
 use serde_json::json;
 use tokio::{fs::File, io::AsyncReadExt};
+#[cfg(feature = "unstable_discord_api")]
+use tracing::warn;
 use tracing::{debug, instrument, trace};
 
 use super::{
@@ -32,6 +36,8 @@ use super::{
     GuildPagination,
     HttpError,
 };
+#[cfg(feature = "unstable_discord_api")]
+use super::InteractionError;
 use crate::constants;
 use crate::http::routing::Route;
 use crate::internal::prelude::*;
@@ -388,9 +394,37 @@ impl Http {
         .await
     }
 
+    /// Creates an emoji owned by an application, with a name and base64-encoded
+    /// image.
+    pub async fn create_application_emoji(
+        &self,
+        application_id: u64,
+        name: &str,
+        image: &str,
+    ) -> Result<Emoji> {
+        let map = json!({
+            "name": name,
+            "image": image,
+        });
+
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateApplicationEmoji {
+                application_id,
+            },
+        })
+        .await
+    }
+
     /// Create a follow-up message for an Interaction.
     ///
     /// Functions the same as [`Self::execute_webhook`]
+    ///
+    /// **Note**: If `wait` is `false`, Discord does not return the created
+    /// message, so its Id is not known and it cannot be edited or deleted
+    /// later. Use [`Self::create_followup_message_and_wait`] to always get
+    /// the message.
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
     pub async fn create_followup_message(
@@ -424,6 +458,37 @@ impl Http {
         response.json::<Message>().await.map(Some).map_err(From::from)
     }
 
+    /// Create a follow-up message for an Interaction, waiting for Discord to
+    /// return the created message.
+    ///
+    /// This is the same as [`Self::create_followup_message`] with `wait` set
+    /// to `true`, which is needed to later edit or delete the message by its
+    /// Id.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub async fn create_followup_message_and_wait(
+        &self,
+        application_id: u64,
+        interaction_token: &str,
+        map: &JsonMap,
+    ) -> Result<Message> {
+        let body = serde_json::to_vec(map)?;
+
+        let mut headers = Headers::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: Some(headers),
+            route: RouteInfo::CreateFollowupMessage {
+                application_id,
+                interaction_token,
+                wait: true,
+            },
+        })
+        .await
+    }
+
     /// Creates a new global command.
     ///
     /// New global commands will be available in all guilds after 1 hour.
@@ -486,6 +551,29 @@ impl Http {
         .await
     }
 
+    /// Overwrites all guild application commands of the given application.
+    ///
+    /// Unlike [`Self::create_guild_application_commands`], this uses the given
+    /// `application_id` rather than the one the client was built with.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub async fn bulk_overwrite_guild_application_commands(
+        &self,
+        application_id: u64,
+        guild_id: u64,
+        map: &Value,
+    ) -> Result<Vec<ApplicationCommand>> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateGuildApplicationCommands {
+                application_id,
+                guild_id,
+            },
+        })
+        .await
+    }
+
     /// Creates a guild with the data provided.
     ///
     /// Only a [`PartialGuild`] will be immediately returned, and a full [`Guild`]
@@ -604,6 +692,65 @@ impl Http {
         .await
     }
 
+    /// Creates a response to an [`Interaction`] from the gateway, giving up if
+    /// Discord does not answer within the given `timeout`.
+    ///
+    /// Discord only accepts a response within 3 seconds of the interaction
+    /// being created, so a warning is logged when the request alone takes up
+    /// more than half of the `timeout`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionError::Timeout`] if the request did not finish
+    /// in time, and an [`InteractionError::TokenExpired`] if Discord rejected
+    /// the interaction token, usually because the response window expired.
+    /// Otherwise returns the same errors as [`Self::create_interaction_response`].
+    ///
+    /// [`InteractionError::Timeout`]: super::InteractionError::Timeout
+    /// [`InteractionError::TokenExpired`]: super::InteractionError::TokenExpired
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub async fn create_interaction_response_with_timeout(
+        &self,
+        interaction_id: u64,
+        interaction_token: &str,
+        map: &Value,
+        timeout: Duration,
+    ) -> Result<()> {
+        let start = Instant::now();
+        let response = self.create_interaction_response(interaction_id, interaction_token, map);
+
+        let result = match tokio::time::timeout(timeout, response).await {
+            Ok(result) => result,
+            Err(_) => {
+                warn!("Interaction response to {} timed out after {:?}", interaction_id, timeout);
+
+                return Err(Error::Http(Box::new(InteractionError::Timeout.into())));
+            },
+        };
+
+        let elapsed = start.elapsed();
+
+        if elapsed > timeout / 2 {
+            warn!(
+                "Interaction response to {} took {:?} out of {:?}",
+                interaction_id, elapsed, timeout
+            );
+        }
+
+        result.map_err(|e| match e {
+            Error::Http(why) => match *why {
+                HttpError::UnsuccessfulRequest(ref response)
+                    if InteractionError::is_token_expired(response) =>
+                {
+                    Error::Http(Box::new(InteractionError::TokenExpired.into()))
+                },
+                why => Error::Http(Box::new(why)),
+            },
+            why => why,
+        })
+    }
+
     /// Creates a [`RichInvite`] for the given [channel][`GuildChannel`].
     ///
     /// Refer to Discord's [docs] for field information.
@@ -701,6 +848,25 @@ impl Http {
         serde_json::from_value(value).map_err(From::from)
     }
 
+    /// Creates a test entitlement to a SKU for a user or guild, which lets
+    /// premium features be tested without paying.
+    ///
+    /// Refer to Discord's [docs] for the fields of the map.
+    ///
+    /// [docs]: https://discord.com/developers/docs/monetization/entitlements#create-test-entitlement
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub async fn create_test_entitlement(&self, map: &Value) -> Result<Entitlement> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateTestEntitlement {
+                application_id: self.application_id,
+            },
+        })
+        .await
+    }
+
     /// Creates a webhook for the given [channel][`GuildChannel`]'s Id, passing in
     /// the given data.
     ///
@@ -768,6 +934,19 @@ impl Http {
         .await
     }
 
+    /// Deletes an emoji owned by an application.
+    pub async fn delete_application_emoji(&self, application_id: u64, emoji_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteApplicationEmoji {
+                application_id,
+                emoji_id,
+            },
+        })
+        .await
+    }
+
     /// Deletes a follow-up message for an interaction.
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
@@ -1006,6 +1185,22 @@ impl Http {
         .await
     }
 
+    /// Deletes a test entitlement created with
+    /// [`Self::create_test_entitlement`].
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub async fn delete_test_entitlement(&self, entitlement_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteTestEntitlement {
+                application_id: self.application_id,
+                entitlement_id,
+            },
+        })
+        .await
+    }
+
     /// Deletes a [`Webhook`] given its Id.
     ///
     /// This method requires authentication, whereas [`Self::delete_webhook_with_token`]
@@ -1099,6 +1294,28 @@ impl Http {
         .await
     }
 
+    /// Changes the name of an emoji owned by an application.
+    pub async fn edit_application_emoji(
+        &self,
+        application_id: u64,
+        emoji_id: u64,
+        name: &str,
+    ) -> Result<Emoji> {
+        let map = json!({
+            "name": name,
+        });
+
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: None,
+            route: RouteInfo::EditApplicationEmoji {
+                application_id,
+                emoji_id,
+            },
+        })
+        .await
+    }
+
     /// Edits a follow-up message for an interaction.
     ///
     /// Refer to Discord's [docs] for Edit Webhook Message for field information.
@@ -2005,6 +2222,27 @@ impl Http {
         .await
     }
 
+    /// Gets all emojis owned by an application.
+    pub async fn list_application_emojis(&self, application_id: u64) -> Result<Vec<Emoji>> {
+        #[derive(Deserialize)]
+        struct ApplicationEmojis {
+            items: Vec<Emoji>,
+        }
+
+        self.request(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetApplicationEmojis {
+                application_id,
+            },
+        })
+        .await?
+        .json::<ApplicationEmojis>()
+        .await
+        .map(|x| x.items)
+        .map_err(From::from)
+    }
+
     /// Gets information about an emoji in a guild.
     pub async fn get_emoji(&self, guild_id: u64, emoji_id: u64) -> Result<Emoji> {
         self.fire(Request {
@@ -2018,6 +2256,48 @@ impl Http {
         .await
     }
 
+    /// Gets the entitlements of the application, optionally only those of
+    /// the given user or guild.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub async fn list_entitlements(
+        &self,
+        user_id: Option<u64>,
+        guild_id: Option<u64>,
+    ) -> Result<Vec<Entitlement>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetEntitlements {
+                application_id: self.application_id,
+                user_id,
+                guild_id,
+            },
+        })
+        .await
+    }
+
+    /// Gets a follow-up message for an interaction.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub async fn get_followup_message(
+        &self,
+        application_id: u64,
+        interaction_token: &str,
+        message_id: u64,
+    ) -> Result<Message> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetFollowupMessage {
+                application_id,
+                interaction_token,
+                message_id,
+            },
+        })
+        .await
+    }
+
     /// Gets current gateway.
     pub async fn get_gateway(&self) -> Result<Gateway> {
         self.fire(Request {
@@ -2444,6 +2724,25 @@ impl Http {
         .await
     }
 
+    /// Gets the initial interaction response.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub async fn get_original_interaction_response(
+        &self,
+        application_id: u64,
+        interaction_token: &str,
+    ) -> Result<Message> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetOriginalInteractionResponse {
+                application_id,
+                interaction_token,
+            },
+        })
+        .await
+    }
+
     /// Gets all pins of a channel.
     pub async fn get_pins(&self, channel_id: u64) -> Result<Vec<Message>> {
         self.fire(Request {
@@ -2481,6 +2780,21 @@ impl Http {
         .await
     }
 
+    /// Gets the SKUs of an application, the premium offerings users or guilds
+    /// can buy.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub async fn list_skus(&self, application_id: u64) -> Result<Vec<Sku>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetSkus {
+                application_id,
+            },
+        })
+        .await
+    }
+
     /// Gets the current unresolved incidents from Discord's Status API.
     ///
     /// Does not require authentication.
@@ -66,8 +66,53 @@ pub enum Error {
     InvalidScheme,
     /// When using a proxy with an invalid port.
     InvalidPort,
+    /// An error specific to responding to an interaction.
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    Interaction(InteractionError),
 }
 
+/// An error returned when responding to an interaction.
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum InteractionError {
+    /// The interaction token is no longer valid, usually because the response
+    /// window has expired.
+    TokenExpired,
+    /// Discord did not answer the request within the given timeout.
+    Timeout,
+}
+
+#[cfg(feature = "unstable_discord_api")]
+impl InteractionError {
+    /// Returns true when the error response indicates that the interaction
+    /// token has expired.
+    ///
+    /// Discord answers with `401 Unauthorized` or with the `Unknown
+    /// interaction` (`10062`) and `Invalid Webhook Token` (`50027`) error codes
+    /// when the token is no longer valid.
+    pub(crate) fn is_token_expired(response: &ErrorResponse) -> bool {
+        response.status_code == StatusCode::UNAUTHORIZED
+            || response.error.code == 10062
+            || response.error.code == 50027
+    }
+}
+
+#[cfg(feature = "unstable_discord_api")]
+impl Display for InteractionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            InteractionError::TokenExpired => f.write_str("The interaction token has expired."),
+            InteractionError::Timeout => f.write_str("The interaction response timed out."),
+        }
+    }
+}
+
+#[cfg(feature = "unstable_discord_api")]
+impl StdError for InteractionError {}
+
 impl Error {
     // We need a freestanding from-function since we cannot implement an async
     // From-trait.
@@ -99,6 +144,13 @@ impl Error {
     }
 }
 
+#[cfg(feature = "unstable_discord_api")]
+impl From<InteractionError> for Error {
+    fn from(error: InteractionError) -> Error {
+        Error::Interaction(error)
+    }
+}
+
 impl From<ErrorResponse> for Error {
     fn from(error: ErrorResponse) -> Error {
         Error::UnsuccessfulRequest(error)
@@ -134,6 +186,8 @@ impl Display for Error {
             Error::Request(_) => f.write_str("Error while sending HTTP request."),
             Error::InvalidScheme => f.write_str("Invalid Url scheme."),
             Error::InvalidPort => f.write_str("Invalid port."),
+            #[cfg(feature = "unstable_discord_api")]
+            Error::Interaction(inner) => Display::fmt(inner, f),
         }
     }
 }
@@ -143,6 +197,8 @@ impl StdError for Error {
         match self {
             Error::Url(inner) => Some(inner),
             Error::Request(inner) => Some(inner),
+            #[cfg(feature = "unstable_discord_api")]
+            Error::Interaction(inner) => Some(inner),
             _ => None,
         }
     }
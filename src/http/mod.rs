@@ -42,6 +42,8 @@ use tokio::fs::File;
 
 pub use self::client::*;
 pub use self::error::Error as HttpError;
+#[cfg(feature = "unstable_discord_api")]
+pub use self::error::InteractionError;
 use self::request::Request;
 pub use self::typing::*;
 #[cfg(feature = "cache")]
@@ -346,6 +346,42 @@ pub enum Route {
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
     ApplicationsIdGuildsIdCommandsId(u64),
+    /// Route for the `/applications/:application_id/entitlements` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    ApplicationsIdEntitlements(u64),
+    /// Route for the `/applications/:application_id/entitlements/:entitlement_id` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    ApplicationsIdEntitlementsId(u64),
+    /// Route for the `/applications/:application_id/skus` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    ApplicationsIdSkus(u64),
+    /// Route for the `/applications/:application_id/emojis` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    ApplicationsIdEmojis(u64),
+    /// Route for the `/applications/:application_id/emojis/:emoji_id` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    ApplicationsIdEmojisId(u64),
     /// Route where no ratelimit headers are in place (i.e. user account-only
     /// routes).
     ///
@@ -798,6 +834,56 @@ impl Route {
     pub fn application_guild_commands_permissions(application_id: u64, guild_id: u64) -> String {
         format!(api!("/applications/{}/guilds/{}/commands/permissions"), application_id, guild_id)
     }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub fn application_entitlement(application_id: u64, entitlement_id: u64) -> String {
+        format!(api!("/applications/{}/entitlements/{}"), application_id, entitlement_id)
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub fn application_entitlements(application_id: u64) -> String {
+        format!(api!("/applications/{}/entitlements"), application_id)
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub fn application_skus(application_id: u64) -> String {
+        format!(api!("/applications/{}/skus"), application_id)
+    }
+
+    pub fn application_emojis(application_id: u64) -> String {
+        format!(api!("/applications/{}/emojis"), application_id)
+    }
+
+    pub fn application_emoji(application_id: u64, emoji_id: u64) -> String {
+        format!(api!("/applications/{}/emojis/{}"), application_id, emoji_id)
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub fn application_entitlements_optioned(
+        application_id: u64,
+        user_id: Option<u64>,
+        guild_id: Option<u64>,
+    ) -> String {
+        let mut s = format!(api!("/applications/{}/entitlements?"), application_id);
+
+        if let Some(user_id) = user_id {
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = write!(s, "&user_id={}", user_id);
+            // should not error, ignoring
+        }
+
+        if let Some(guild_id) = guild_id {
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = write!(s, "&guild_id={}", guild_id);
+            // should not error, ignoring
+        }
+
+        s
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -817,6 +903,9 @@ pub enum RouteInfo<'a> {
     BroadcastTyping {
         channel_id: u64,
     },
+    CreateApplicationEmoji {
+        application_id: u64,
+    },
     CreateChannel {
         guild_id: u64,
     },
@@ -882,9 +971,18 @@ pub enum RouteInfo<'a> {
     CreateRole {
         guild_id: u64,
     },
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    CreateTestEntitlement {
+        application_id: u64,
+    },
     CreateWebhook {
         channel_id: u64,
     },
+    DeleteApplicationEmoji {
+        application_id: u64,
+        emoji_id: u64,
+    },
     DeleteChannel {
         channel_id: u64,
     },
@@ -958,6 +1056,12 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         role_id: u64,
     },
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    DeleteTestEntitlement {
+        application_id: u64,
+        entitlement_id: u64,
+    },
     DeleteWebhook {
         webhook_id: u64,
     },
@@ -970,6 +1074,10 @@ pub enum RouteInfo<'a> {
         webhook_id: u64,
         message_id: u64,
     },
+    EditApplicationEmoji {
+        application_id: u64,
+        emoji_id: u64,
+    },
     EditChannel {
         channel_id: u64,
     },
@@ -1073,6 +1181,9 @@ pub enum RouteInfo<'a> {
         webhook_id: u64,
     },
     GetActiveMaintenance,
+    GetApplicationEmojis {
+        application_id: u64,
+    },
     GetAuditLogs {
         action_type: Option<u8>,
         before: Option<u64>,
@@ -1105,6 +1216,20 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         emoji_id: u64,
     },
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    GetEntitlements {
+        application_id: u64,
+        user_id: Option<u64>,
+        guild_id: Option<u64>,
+    },
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    GetFollowupMessage {
+        application_id: u64,
+        interaction_token: &'a str,
+        message_id: u64,
+    },
     GetGateway,
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
@@ -1197,6 +1322,12 @@ pub enum RouteInfo<'a> {
         channel_id: u64,
         query: String,
     },
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    GetOriginalInteractionResponse {
+        application_id: u64,
+        interaction_token: &'a str,
+    },
     GetPins {
         channel_id: u64,
     },
@@ -1207,6 +1338,11 @@ pub enum RouteInfo<'a> {
         message_id: u64,
         reaction: String,
     },
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    GetSkus {
+        application_id: u64,
+    },
     GetUnresolvedIncidents,
     GetUpcomingMaintenances,
     GetUser {
@@ -1297,6 +1433,13 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdTyping(channel_id),
                 Cow::from(Route::channel_typing(channel_id)),
             ),
+            RouteInfo::CreateApplicationEmoji {
+                application_id,
+            } => (
+                LightMethod::Post,
+                Route::ApplicationsIdEmojis(application_id),
+                Cow::from(Route::application_emojis(application_id)),
+            ),
             RouteInfo::CreateChannel {
                 guild_id,
             } => (
@@ -1430,6 +1573,14 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdCrosspostsMessageId(channel_id),
                 Cow::from(Route::channel_message_crosspost(channel_id, message_id)),
             ),
+            #[cfg(feature = "unstable_discord_api")]
+            RouteInfo::CreateTestEntitlement {
+                application_id,
+            } => (
+                LightMethod::Post,
+                Route::ApplicationsIdEntitlements(application_id),
+                Cow::from(Route::application_entitlements(application_id)),
+            ),
             RouteInfo::CreateWebhook {
                 channel_id,
             } => (
@@ -1437,6 +1588,14 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdWebhooks(channel_id),
                 Cow::from(Route::channel_webhooks(channel_id)),
             ),
+            RouteInfo::DeleteApplicationEmoji {
+                application_id,
+                emoji_id,
+            } => (
+                LightMethod::Delete,
+                Route::ApplicationsIdEmojisId(application_id),
+                Cow::from(Route::application_emoji(application_id, emoji_id)),
+            ),
             RouteInfo::DeleteChannel {
                 channel_id,
             } => (
@@ -1571,6 +1730,15 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRolesId(guild_id),
                 Cow::from(Route::guild_role(guild_id, role_id)),
             ),
+            #[cfg(feature = "unstable_discord_api")]
+            RouteInfo::DeleteTestEntitlement {
+                application_id,
+                entitlement_id,
+            } => (
+                LightMethod::Delete,
+                Route::ApplicationsIdEntitlementsId(application_id),
+                Cow::from(Route::application_entitlement(application_id, entitlement_id)),
+            ),
             RouteInfo::DeleteWebhook {
                 webhook_id,
             } => (
@@ -1595,6 +1763,14 @@ impl<'a> RouteInfo<'a> {
                 Route::WebhooksIdMessagesId(webhook_id),
                 Cow::from(Route::webhook_message(webhook_id, token, message_id)),
             ),
+            RouteInfo::EditApplicationEmoji {
+                application_id,
+                emoji_id,
+            } => (
+                LightMethod::Patch,
+                Route::ApplicationsIdEmojisId(application_id),
+                Cow::from(Route::application_emoji(application_id, emoji_id)),
+            ),
             RouteInfo::EditChannel {
                 channel_id,
             } => (
@@ -1787,6 +1963,13 @@ impl<'a> RouteInfo<'a> {
             RouteInfo::GetActiveMaintenance => {
                 (LightMethod::Get, Route::None, Cow::from(Route::status_maintenances_active()))
             },
+            RouteInfo::GetApplicationEmojis {
+                application_id,
+            } => (
+                LightMethod::Get,
+                Route::ApplicationsIdEmojis(application_id),
+                Cow::from(Route::application_emojis(application_id)),
+            ),
             RouteInfo::GetAuditLogs {
                 action_type,
                 before,
@@ -1857,6 +2040,34 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojisId(guild_id),
                 Cow::from(Route::guild_emoji(guild_id, emoji_id)),
             ),
+            #[cfg(feature = "unstable_discord_api")]
+            RouteInfo::GetEntitlements {
+                application_id,
+                user_id,
+                guild_id,
+            } => (
+                LightMethod::Get,
+                Route::ApplicationsIdEntitlements(application_id),
+                Cow::from(Route::application_entitlements_optioned(
+                    application_id,
+                    user_id,
+                    guild_id,
+                )),
+            ),
+            #[cfg(feature = "unstable_discord_api")]
+            RouteInfo::GetFollowupMessage {
+                application_id,
+                interaction_token,
+                message_id,
+            } => (
+                LightMethod::Get,
+                Route::WebhooksApplicationId(application_id),
+                Cow::from(Route::webhook_followup_message(
+                    application_id,
+                    interaction_token,
+                    message_id,
+                )),
+            ),
             RouteInfo::GetGateway => {
                 (LightMethod::Get, Route::Gateway, Cow::from(Route::gateway()))
             },
@@ -2029,6 +2240,18 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdMessages(channel_id),
                 Cow::from(Route::channel_messages(channel_id, Some(query.as_ref()))),
             ),
+            #[cfg(feature = "unstable_discord_api")]
+            RouteInfo::GetOriginalInteractionResponse {
+                application_id,
+                interaction_token,
+            } => (
+                LightMethod::Get,
+                Route::WebhooksApplicationId(application_id),
+                Cow::from(Route::webhook_original_interaction_response(
+                    application_id,
+                    interaction_token,
+                )),
+            ),
             RouteInfo::GetPins {
                 channel_id,
             } => (
@@ -2049,6 +2272,14 @@ impl<'a> RouteInfo<'a> {
                     channel_id, message_id, reaction, limit, after,
                 )),
             ),
+            #[cfg(feature = "unstable_discord_api")]
+            RouteInfo::GetSkus {
+                application_id,
+            } => (
+                LightMethod::Get,
+                Route::ApplicationsIdSkus(application_id),
+                Cow::from(Route::application_skus(application_id)),
+            ),
             RouteInfo::GetUnresolvedIncidents => {
                 (LightMethod::Get, Route::None, Cow::from(Route::status_incidents_unresolved()))
             },
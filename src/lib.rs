@@ -109,6 +109,9 @@ use std::sync::Arc;
 #[cfg(all(feature = "client", feature = "cache"))]
 use std::time::Duration;
 
+#[cfg(all(feature = "client", feature = "unstable_discord_api"))]
+use tokio::sync::broadcast;
+
 #[cfg(all(feature = "client", feature = "cache"))]
 use crate::cache::Cache;
 #[cfg(all(feature = "client", feature = "gateway"))]
@@ -116,6 +119,8 @@ pub use crate::client::Client;
 pub use crate::error::{Error, Result};
 #[cfg(feature = "client")]
 use crate::http::Http;
+#[cfg(all(feature = "client", feature = "unstable_discord_api"))]
+use crate::model::interactions::Interaction;
 
 #[cfg(feature = "client")]
 #[derive(Clone, Default)]
@@ -126,6 +131,27 @@ pub struct CacheAndHttp {
     #[cfg(feature = "cache")]
     pub update_cache_timeout: Option<Duration>,
     pub http: Arc<Http>,
+    #[cfg(feature = "unstable_discord_api")]
+    pub(crate) interaction_broadcast: Option<broadcast::Sender<Arc<Interaction>>>,
+}
+
+#[cfg(all(feature = "client", feature = "unstable_discord_api"))]
+impl CacheAndHttp {
+    /// Subscribes to the interactions received by the client, as
+    /// [`Context::interaction_broadcast`] does.
+    ///
+    /// **Note**: If this was not created by a [`Client`], the returned
+    /// receiver is closed and never receives an interaction.
+    ///
+    /// [`Context::interaction_broadcast`]: crate::client::Context::interaction_broadcast
+    /// [`Client`]: crate::client::Client
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub fn interaction_broadcast(&self) -> broadcast::Receiver<Arc<Interaction>> {
+        match &self.interaction_broadcast {
+            Some(sender) => sender.subscribe(),
+            None => broadcast::channel(1).1,
+        }
+    }
 }
 
 // For the procedural macros in `command_attr`.
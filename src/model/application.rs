@@ -2,7 +2,22 @@
 
 use std::fmt;
 
-use super::{id::UserId, user::User, utils::*};
+use bitflags::__impl_bitflags;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+pub use super::team::{Team, TeamMember, TeamMemberRole, TeamMembershipState as MembershipState};
+#[cfg(feature = "model")]
+use super::{guild::Emoji, id::EmojiId};
+use super::{
+    id::{ApplicationId, GuildId, UserId},
+    permissions::Permissions,
+    user::User,
+    utils::*,
+};
+#[cfg(feature = "model")]
+use crate::http::Http;
+use crate::internal::prelude::*;
 
 /// Information about a user's application. An application does not necessarily
 /// have an associated bot user.
@@ -45,6 +60,10 @@ pub struct ApplicationInfo {
     ///
     /// This is not equivalent to the application's bot user's token.
     pub secret: String,
+    /// The default settings of the in-app authorization link, if it is
+    /// enabled.
+    #[serde(default)]
+    pub install_params: Option<InstallParams>,
 }
 
 impl fmt::Debug for ApplicationInfo {
@@ -61,6 +80,7 @@ impl fmt::Debug for ApplicationInfo {
             .field("redirect_uris", &self.redirect_uris)
             .field("rpc_origins", &self.rpc_origins)
             .field("team", &self.team)
+            .field("install_params", &self.install_params)
             .finish()
     }
 }
@@ -120,56 +140,256 @@ pub struct PartialCurrentApplicationInfo {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct CurrentApplicationInfo {
+    /// The description of the application.
     pub description: String,
+    /// The hash of the icon of the application.
     pub icon: Option<String>,
+    /// The Id of the application.
     pub id: UserId,
+    /// The name of the application.
     pub name: String,
+    /// The user who owns the application.
+    ///
+    /// **Note**: If the application belongs to a [`Team`], this is a fake
+    /// user representing the team.
     pub owner: User,
+    /// The RPC origin URLs, if RPC is enabled.
     #[serde(default)]
     pub rpc_origins: Vec<String>,
+    /// Whether users other than the owner can add the bot to guilds.
     pub bot_public: bool,
+    /// Whether the bot requires the full OAuth2 code grant flow to join.
     pub bot_require_code_grant: bool,
+    /// The team owning the application, if there is one.
     pub team: Option<Team>,
+    /// The URL of the terms of service of the application.
+    #[serde(default)]
+    pub terms_of_service_url: Option<String>,
+    /// The URL of the privacy policy of the application.
+    #[serde(default)]
+    pub privacy_policy_url: Option<String>,
+    /// The hex encoded key used to verify interactions sent over HTTP.
+    #[serde(default)]
+    pub verify_key: String,
+    /// The Id of the guild the application is linked to, if it is sold on
+    /// Discord.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+    /// The URL slug of the game store page, if the application is sold on
+    /// Discord.
+    #[serde(default)]
+    pub slug: Option<String>,
+    /// The hash of the default rich presence invite cover image.
+    #[serde(default)]
+    pub cover_image: Option<String>,
+    /// The public flags of the application.
+    #[serde(default)]
+    pub flags: Option<ApplicationFlags>,
+    /// The tags describing the application, up to 5.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The default settings of the in-app authorization link, if it is
+    /// enabled.
+    #[serde(default)]
+    pub install_params: Option<InstallParams>,
 }
 
-/// Information about the Team group of the application.
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct Team {
-    /// The icon of the team.
-    pub icon: Option<String>,
-    /// The snowflake ID of the team.
-    #[serde(deserialize_with = "deserialize_u64")]
-    pub id: u64,
-    /// The members of the team
-    pub members: Vec<TeamMember>,
-    /// The user id of the team owner.
-    pub owner_user_id: UserId,
+impl CurrentApplicationInfo {
+    /// Gets the Id of the application as an [`ApplicationId`].
+    ///
+    /// [`id`] holds the same value, as the Id of a bot user is the Id of its
+    /// application.
+    ///
+    /// [`id`]: Self::id
+    #[inline]
+    pub fn application_id(&self) -> ApplicationId {
+        ApplicationId(self.id.0)
+    }
+}
+
+/// The public flags of an application.
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct ApplicationFlags {
+    pub bits: u64,
 }
 
-/// Infromation about a Member on a Team.
+__impl_bitflags! {
+    ApplicationFlags: u64 {
+        /// The application uses the auto moderation API.
+        APPLICATION_AUTO_MODERATION_RULE_CREATE_BADGE = 1 << 6;
+        /// The application is verified and may receive presence updates.
+        GATEWAY_PRESENCE = 1 << 12;
+        /// The application is unverified and may receive presence updates.
+        GATEWAY_PRESENCE_LIMITED = 1 << 13;
+        /// The application is verified and may receive guild member events.
+        GATEWAY_GUILD_MEMBERS = 1 << 14;
+        /// The application is unverified and may receive guild member events.
+        GATEWAY_GUILD_MEMBERS_LIMITED = 1 << 15;
+        /// The application's verification is pending because it reached the
+        /// unverified guild limit.
+        VERIFICATION_PENDING_GUILD_LIMIT = 1 << 16;
+        /// The application is embedded in the Discord client.
+        EMBEDDED = 1 << 17;
+        /// The application is verified and may receive message content.
+        GATEWAY_MESSAGE_CONTENT = 1 << 18;
+        /// The application is unverified and may receive message content.
+        GATEWAY_MESSAGE_CONTENT_LIMITED = 1 << 19;
+        /// The application has at least one global application command.
+        APPLICATION_COMMAND_BADGE = 1 << 23;
+    }
+}
+
+impl<'de> Deserialize<'de> for ApplicationFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(ApplicationFlags::from_bits_truncate(deserializer.deserialize_any(U64Visitor)?))
+    }
+}
+
+impl Serialize for ApplicationFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+/// The settings used to add an application to a guild or user.
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct TeamMember {
-    /// The member's membership state.
-    pub membership_state: MembershipState,
-    /// The list of permissions of the member on the team.
-    ///
-    /// NOTE: Will always be ["*"] for now.
-    pub permissions: Vec<String>,
-    /// The ID of the team they are a member of.
-    #[serde(deserialize_with = "deserialize_u64")]
-    pub team_id: u64,
-    /// The user type of the team member.
-    pub user: User,
+#[non_exhaustive]
+pub struct InstallParams {
+    /// The OAuth2 scopes to add the application with.
+    pub scopes: Vec<String>,
+    /// The permissions to request for the bot role.
+    pub permissions: Permissions,
+}
+
+impl InstallParams {
+    /// Generates the OAuth2 URL that adds the application with the given
+    /// client Id using these settings.
+    pub fn generate_install_url(&self, client_id: u64) -> String {
+        format!(
+            "https://discord.com/api/oauth2/authorize?client_id={}&scope={}&permissions={}",
+            client_id,
+            self.scopes.join("%20"),
+            self.permissions.bits()
+        )
+    }
 }
 
-#[derive(Clone, Debug, Copy, PartialEq)]
-pub enum MembershipState {
-    Invited = 1,
-    Accepted = 2,
-    Unknown = !0,
+#[cfg(feature = "model")]
+impl ApplicationId {
+    /// Gets all emojis owned by the application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the application is not the current one.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    #[inline]
+    pub async fn list_emojis(self, http: impl AsRef<Http>) -> Result<Vec<Emoji>> {
+        http.as_ref().list_application_emojis(self.0).await
+    }
+
+    /// Creates an emoji owned by the application with a name and
+    /// base64-encoded image.
+    ///
+    /// See the [`EditProfile::avatar`] example for how to read an image from
+    /// the filesystem and encode it as base64.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the name is invalid or taken, or if the
+    /// image is too big.
+    ///
+    /// [`EditProfile::avatar`]: crate::builder::EditProfile::avatar
+    /// [`Error::Http`]: crate::error::Error::Http
+    #[inline]
+    pub async fn create_emoji(
+        self,
+        http: impl AsRef<Http>,
+        name: &str,
+        image: &str,
+    ) -> Result<Emoji> {
+        http.as_ref().create_application_emoji(self.0, name, image).await
+    }
+
+    /// Renames an emoji owned by the application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the name is invalid, or if an emoji with
+    /// that Id does not exist.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    #[inline]
+    pub async fn edit_emoji(
+        self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+        name: &str,
+    ) -> Result<Emoji> {
+        http.as_ref().edit_application_emoji(self.0, emoji_id.into().0, name).await
+    }
+
+    /// Deletes an emoji owned by the application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if an emoji with that Id does not exist.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    #[inline]
+    pub async fn delete_emoji(
+        self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+    ) -> Result<()> {
+        http.as_ref().delete_application_emoji(self.0, emoji_id.into().0).await
+    }
 }
 
-enum_number!(MembershipState {
-    Invited,
-    Accepted
-});
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_current_application_info() {
+        let info: CurrentApplicationInfo = serde_json::from_value(json!({
+            "id": "1",
+            "name": "bot",
+            "description": "",
+            "icon": null,
+            "owner": {"id": "2", "username": "owner", "discriminator": "0001", "avatar": null},
+            "bot_public": true,
+            "bot_require_code_grant": false,
+            "team": null,
+            "verify_key": "abc",
+            "flags": 8388608,
+            "tags": ["music"],
+        }))
+        .unwrap();
+
+        assert_eq!(info.id, UserId(1));
+        assert_eq!(info.verify_key, "abc");
+        assert_eq!(info.application_id(), ApplicationId(1));
+        assert_eq!(info.flags, Some(ApplicationFlags::APPLICATION_COMMAND_BADGE));
+        assert_eq!(info.tags, ["music"]);
+        assert!(info.guild_id.is_none());
+        assert!(info.install_params.is_none());
+    }
+
+    #[test]
+    fn test_install_url() {
+        let params = InstallParams {
+            scopes: vec!["bot".to_string(), "applications.commands".to_string()],
+            permissions: Permissions::SEND_MESSAGES,
+        };
+
+        assert_eq!(
+            params.generate_install_url(1),
+            "https://discord.com/api/oauth2/authorize?client_id=1\
+             &scope=bot%20applications.commands&permissions=2048"
+        );
+    }
+}
@@ -903,6 +903,51 @@ impl Message {
         Ok(())
     }
 
+    /// Checks that no select menu in the `components` of the message, or of
+    /// the `data` of an interaction response, has more options selected by
+    /// default than its maximum number of values.
+    #[cfg(feature = "unstable_discord_api")]
+    pub(crate) fn check_components(map: &JsonMap) -> Result<()> {
+        use crate::model::interactions::ComponentType;
+
+        let rows = match map
+            .get("components")
+            .or_else(|| map.get("data").and_then(|data| data.get("components")))
+            .and_then(Value::as_array)
+        {
+            Some(rows) => rows,
+            None => return Ok(()),
+        };
+
+        let menus = rows
+            .iter()
+            .filter_map(|row| row.get("components").and_then(Value::as_array))
+            .flatten()
+            .filter(|component| {
+                component.get("type").and_then(Value::as_u64)
+                    == Some(ComponentType::SelectMenu as u64)
+            });
+
+        for menu in menus {
+            let max = menu.get("max_values").and_then(Value::as_u64).unwrap_or(1);
+            let defaults = match menu.get("options").and_then(Value::as_array) {
+                Some(options) => {
+                    options.iter().filter(|o| o.get("default") == Some(&Value::Bool(true))).count()
+                },
+                None => 0,
+            };
+
+            if defaults as u64 > max {
+                return Err(Error::Model(ModelError::TooManyDefaultOptions {
+                    max,
+                    got: defaults,
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn check_embed_length(map: &JsonMap) -> Result<()> {
         let embed = match map.get("embed") {
             Some(&Value::Object(ref value)) => value,
@@ -245,9 +245,11 @@ impl<'de> Deserialize<'de> for Channel {
         };
 
         match kind {
-            0 | 2 | 5 | 6 | 13 => serde_json::from_value::<GuildChannel>(Value::Object(v))
-                .map(Channel::Guild)
-                .map_err(DeError::custom),
+            0 | 2 | 5 | 6 | 13 | 15 | 16 => {
+                serde_json::from_value::<GuildChannel>(Value::Object(v))
+                    .map(Channel::Guild)
+                    .map_err(DeError::custom)
+            },
             1 => serde_json::from_value::<PrivateChannel>(Value::Object(v))
                 .map(Channel::Private)
                 .map_err(DeError::custom),
@@ -309,8 +311,24 @@ pub enum ChannelType {
     ///
     /// Note: `StoreChannel` is serialized into a [`GuildChannel`]
     Store = 6,
+    /// An indicator that the channel is a thread in a `NewsChannel`.
+    NewsThread = 10,
+    /// An indicator that the channel is a public thread in a text
+    /// [`GuildChannel`].
+    PublicThread = 11,
+    /// An indicator that the channel is a private thread in a text
+    /// [`GuildChannel`], only visible to invited members and moderators.
+    PrivateThread = 12,
     /// An indicator that the channel is a stage [`GuildChannel`].
     Stage = 13,
+    /// An indicator that the channel is the channel of a student hub directory.
+    Directory = 14,
+    /// An indicator that the channel is a forum [`GuildChannel`], which can
+    /// only contain threads.
+    Forum = 15,
+    /// An indicator that the channel is a media [`GuildChannel`], which is
+    /// like a forum but only for posts with media.
+    Media = 16,
     /// An indicator that the channel is of unknown type.
     Unknown = !0,
 }
@@ -322,7 +340,13 @@ enum_number!(ChannelType {
     Category,
     News,
     Store,
-    Stage
+    NewsThread,
+    PublicThread,
+    PrivateThread,
+    Stage,
+    Directory,
+    Forum,
+    Media
 });
 
 impl ChannelType {
@@ -335,10 +359,36 @@ impl ChannelType {
             ChannelType::Category => "category",
             ChannelType::News => "news",
             ChannelType::Store => "store",
+            ChannelType::NewsThread => "news_thread",
+            ChannelType::PublicThread => "public_thread",
+            ChannelType::PrivateThread => "private_thread",
             ChannelType::Stage => "stage",
+            ChannelType::Directory => "directory",
+            ChannelType::Forum => "forum",
+            ChannelType::Media => "media",
             ChannelType::Unknown => "unknown",
         }
     }
+
+    /// Whether the channel is a thread.
+    #[inline]
+    pub fn is_thread(&self) -> bool {
+        matches!(
+            self,
+            ChannelType::NewsThread | ChannelType::PublicThread | ChannelType::PrivateThread
+        )
+    }
+
+    /// Whether slash commands can be used in the channel.
+    ///
+    /// This is only the case for channels where messages can be sent, which
+    /// excludes voice and stage channels, as well as channels which are only
+    /// containers, such as categories and forums.
+    #[inline]
+    pub fn supports_slash_commands(&self) -> bool {
+        matches!(self, ChannelType::Text | ChannelType::Private | ChannelType::News)
+            || self.is_thread()
+    }
 }
 
 #[derive(Deserialize, Serialize)]
@@ -503,6 +553,19 @@ mod test {
             assert!(!private_channel.is_nsfw());
         }
     }
+
+    #[test]
+    fn channel_type_checks() {
+        use super::ChannelType;
+
+        assert!(ChannelType::PrivateThread.is_thread());
+        assert!(!ChannelType::Forum.is_thread());
+
+        assert!(ChannelType::Text.supports_slash_commands());
+        assert!(ChannelType::NewsThread.supports_slash_commands());
+        assert!(!ChannelType::Voice.supports_slash_commands());
+        assert!(!ChannelType::Stage.supports_slash_commands());
+    }
 }
 
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
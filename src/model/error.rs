@@ -162,6 +162,35 @@ pub enum Error {
     NotAuthor,
     /// Indicates that the webhook token is missing.
     NoTokenSet,
+    /// Indicates that an interaction has no option with the given name.
+    OptionNotFound(String),
+    /// Indicates that the option with the given name has a different type than
+    /// the one requested.
+    InvalidOptionType(String),
+    /// Indicates that an interaction was not sent from a channel, as is the
+    /// case for pings.
+    InvalidChannelInteraction,
+    /// Indicates that a select menu has more options selected by default than
+    /// its maximum number of selected values.
+    ///
+    /// The maximum allowed and the number of default options are provided.
+    TooManyDefaultOptions {
+        max: u64,
+        got: usize,
+    },
+    /// Indicates that an application command was given more permissions than
+    /// Discord allows.
+    ///
+    /// The maximum allowed and the number of permissions are provided.
+    TooManyPermissions {
+        max: usize,
+        got: usize,
+    },
+    /// Indicates that an application command exceeds limits imposed by
+    /// Discord, such as the length of its name.
+    ///
+    /// A description of every exceeded limit is provided.
+    InvalidApplicationCommand(Vec<String>),
 }
 
 impl Error {
@@ -203,6 +232,18 @@ impl Display for Error {
             Error::NameTooLong => f.write_str("Name is over the character limit."),
             Error::NotAuthor => f.write_str("The bot is not author of this message."),
             Error::NoTokenSet => f.write_str("Token is not set."),
+            Error::OptionNotFound(_) => f.write_str("Option not found."),
+            Error::InvalidOptionType(_) => f.write_str("Option has a different type."),
+            Error::InvalidChannelInteraction => {
+                f.write_str("The interaction was not sent from a channel.")
+            },
+            Error::TooManyDefaultOptions {
+                ..
+            } => f.write_str("Too many options selected by default."),
+            Error::TooManyPermissions {
+                ..
+            } => f.write_str("Too many permissions."),
+            Error::InvalidApplicationCommand(_) => f.write_str("Invalid application command."),
         }
     }
 }
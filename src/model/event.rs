@@ -1444,6 +1444,166 @@ pub struct IntegrationDeleteEvent {
     pub application_id: Option<ApplicationId>,
 }
 
+/// Event data for the entitlement create event.
+///
+/// This is fired when an [`Entitlement`] is created, such as when a user subscribes to a SKU.
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct EntitlementCreateEvent {
+    pub entitlement: Entitlement,
+}
+
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+impl<'de> Deserialize<'de> for EntitlementCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            entitlement: Entitlement::deserialize(deserializer)?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+impl Serialize for EntitlementCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Entitlement::serialize(&self.entitlement, serializer)
+    }
+}
+
+/// Event data for the entitlement update event.
+///
+/// This is fired when an [`Entitlement`] is updated, such as when a subscription renews.
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct EntitlementUpdateEvent {
+    pub entitlement: Entitlement,
+}
+
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+impl<'de> Deserialize<'de> for EntitlementUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            entitlement: Entitlement::deserialize(deserializer)?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+impl Serialize for EntitlementUpdateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Entitlement::serialize(&self.entitlement, serializer)
+    }
+}
+
+/// Event data for the entitlement delete event.
+///
+/// This is fired when an [`Entitlement`] is deleted, such as when it is refunded.
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct EntitlementDeleteEvent {
+    pub entitlement: Entitlement,
+}
+
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+impl<'de> Deserialize<'de> for EntitlementDeleteEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            entitlement: Entitlement::deserialize(deserializer)?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+impl Serialize for EntitlementDeleteEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Entitlement::serialize(&self.entitlement, serializer)
+    }
+}
+
+/// Event data for the poll vote add event.
+///
+/// This is fired when a user votes for an answer of a poll.
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct PollVoteAddEvent {
+    pub vote: PollAnswerVote,
+}
+
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+impl<'de> Deserialize<'de> for PollVoteAddEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            vote: PollAnswerVote::deserialize(deserializer)?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+impl Serialize for PollVoteAddEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        PollAnswerVote::serialize(&self.vote, serializer)
+    }
+}
+
+/// Event data for the poll vote remove event.
+///
+/// This is fired when a user removes their vote for an answer of a poll.
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct PollVoteRemoveEvent {
+    pub vote: PollAnswerVote,
+}
+
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+impl<'de> Deserialize<'de> for PollVoteRemoveEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            vote: PollAnswerVote::deserialize(deserializer)?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+impl Serialize for PollVoteRemoveEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        PollAnswerVote::serialize(&self.vote, serializer)
+    }
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Serialize)]
 #[non_exhaustive]
@@ -1646,6 +1806,26 @@ pub enum Event {
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
     IntegrationDelete(IntegrationDeleteEvent),
+    /// An entitlement was created
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    EntitlementCreate(EntitlementCreateEvent),
+    /// An entitlement was updated
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    EntitlementUpdate(EntitlementUpdateEvent),
+    /// An entitlement was deleted
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    EntitlementDelete(EntitlementDeleteEvent),
+    /// A user voted for an answer of a poll
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    PollVoteAdd(PollVoteAddEvent),
+    /// A user removed their vote for an answer of a poll
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    PollVoteRemove(PollVoteRemoveEvent),
     /// An event type not covered by the above
     Unknown(UnknownEvent),
 }
@@ -1699,6 +1879,16 @@ impl Event {
             Self::IntegrationUpdate(_) => EventType::IntegrationUpdate,
             #[cfg(feature = "unstable_discord_api")]
             Self::IntegrationDelete(_) => EventType::IntegrationDelete,
+            #[cfg(feature = "unstable_discord_api")]
+            Self::EntitlementCreate(_) => EventType::EntitlementCreate,
+            #[cfg(feature = "unstable_discord_api")]
+            Self::EntitlementUpdate(_) => EventType::EntitlementUpdate,
+            #[cfg(feature = "unstable_discord_api")]
+            Self::EntitlementDelete(_) => EventType::EntitlementDelete,
+            #[cfg(feature = "unstable_discord_api")]
+            Self::PollVoteAdd(_) => EventType::PollVoteAdd,
+            #[cfg(feature = "unstable_discord_api")]
+            Self::PollVoteRemove(_) => EventType::PollVoteRemove,
             Self::Unknown(unknown) => EventType::Other(unknown.kind.clone()),
         }
     }
@@ -1790,6 +1980,16 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
         EventType::IntegrationUpdate => Event::IntegrationUpdate(serde_json::from_value(v)?),
         #[cfg(feature = "unstable_discord_api")]
         EventType::IntegrationDelete => Event::IntegrationDelete(serde_json::from_value(v)?),
+        #[cfg(feature = "unstable_discord_api")]
+        EventType::EntitlementCreate => Event::EntitlementCreate(serde_json::from_value(v)?),
+        #[cfg(feature = "unstable_discord_api")]
+        EventType::EntitlementUpdate => Event::EntitlementUpdate(serde_json::from_value(v)?),
+        #[cfg(feature = "unstable_discord_api")]
+        EventType::EntitlementDelete => Event::EntitlementDelete(serde_json::from_value(v)?),
+        #[cfg(feature = "unstable_discord_api")]
+        EventType::PollVoteAdd => Event::PollVoteAdd(serde_json::from_value(v)?),
+        #[cfg(feature = "unstable_discord_api")]
+        EventType::PollVoteRemove => Event::PollVoteRemove(serde_json::from_value(v)?),
         EventType::Other(kind) => Event::Unknown(UnknownEvent {
             kind,
             value: v,
@@ -1976,6 +2176,31 @@ pub enum EventType {
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
     IntegrationDelete,
+    /// Indicator that an entitlement was created.
+    /// This maps to [`EntitlementCreateEvent`].
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    EntitlementCreate,
+    /// Indicator that an entitlement was updated.
+    /// This maps to [`EntitlementUpdateEvent`].
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    EntitlementUpdate,
+    /// Indicator that an entitlement was deleted.
+    /// This maps to [`EntitlementDeleteEvent`].
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    EntitlementDelete,
+    /// Indicator that a user voted for an answer of a poll.
+    /// This maps to [`PollVoteAddEvent`].
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    PollVoteAdd,
+    /// Indicator that a user removed their vote for an answer of a poll.
+    /// This maps to [`PollVoteRemoveEvent`].
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    PollVoteRemove,
     /// An unknown event was received over the gateway.
     ///
     /// This should be logged so that support for it can be added in the
@@ -2038,6 +2263,21 @@ impl EventType {
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
     const INTEGRATION_DELETE: &'static str = "INTEGRATION_DELETE";
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    const ENTITLEMENT_CREATE: &'static str = "ENTITLEMENT_CREATE";
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    const ENTITLEMENT_UPDATE: &'static str = "ENTITLEMENT_UPDATE";
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    const ENTITLEMENT_DELETE: &'static str = "ENTITLEMENT_DELETE";
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    const MESSAGE_POLL_VOTE_ADD: &'static str = "MESSAGE_POLL_VOTE_ADD";
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    const MESSAGE_POLL_VOTE_REMOVE: &'static str = "MESSAGE_POLL_VOTE_REMOVE";
 
     /// Return the event name of this event. Some events are synthetic, and we lack
     /// the information to recover the original event name for these events, in which
@@ -2088,6 +2328,16 @@ impl EventType {
             Self::IntegrationUpdate => Some(Self::INTEGRATION_UPDATE),
             #[cfg(feature = "unstable_discord_api")]
             Self::IntegrationDelete => Some(Self::INTEGRATION_DELETE),
+            #[cfg(feature = "unstable_discord_api")]
+            Self::EntitlementCreate => Some(Self::ENTITLEMENT_CREATE),
+            #[cfg(feature = "unstable_discord_api")]
+            Self::EntitlementUpdate => Some(Self::ENTITLEMENT_UPDATE),
+            #[cfg(feature = "unstable_discord_api")]
+            Self::EntitlementDelete => Some(Self::ENTITLEMENT_DELETE),
+            #[cfg(feature = "unstable_discord_api")]
+            Self::PollVoteAdd => Some(Self::MESSAGE_POLL_VOTE_ADD),
+            #[cfg(feature = "unstable_discord_api")]
+            Self::PollVoteRemove => Some(Self::MESSAGE_POLL_VOTE_REMOVE),
             // GuildUnavailable is a synthetic event type, corresponding to either
             // `GUILD_CREATE` or `GUILD_DELETE`, but we don't have enough information
             // to recover the name here, so we return `None` instead.
@@ -2160,6 +2410,16 @@ impl<'de> Deserialize<'de> for EventType {
                     EventType::INTEGRATION_UPDATE => EventType::IntegrationUpdate,
                     #[cfg(feature = "unstable_discord_api")]
                     EventType::INTEGRATION_DELETE => EventType::IntegrationDelete,
+                    #[cfg(feature = "unstable_discord_api")]
+                    EventType::ENTITLEMENT_CREATE => EventType::EntitlementCreate,
+                    #[cfg(feature = "unstable_discord_api")]
+                    EventType::ENTITLEMENT_UPDATE => EventType::EntitlementUpdate,
+                    #[cfg(feature = "unstable_discord_api")]
+                    EventType::ENTITLEMENT_DELETE => EventType::EntitlementDelete,
+                    #[cfg(feature = "unstable_discord_api")]
+                    EventType::MESSAGE_POLL_VOTE_ADD => EventType::PollVoteAdd,
+                    #[cfg(feature = "unstable_discord_api")]
+                    EventType::MESSAGE_POLL_VOTE_REMOVE => EventType::PollVoteRemove,
                     other => EventType::Other(other.to_owned()),
                 })
             }
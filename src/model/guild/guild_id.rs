@@ -1064,13 +1064,18 @@ impl GuildId {
     where
         F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand,
     {
-        let map = ApplicationCommand::build_application_command(f);
+        let map = ApplicationCommand::build_application_command(f, false)?;
         http.as_ref().create_guild_application_command(self.0, &Value::Object(map)).await
     }
 
     /// Same as [`create_application_command`], but allows to create more
     /// than one command per call.
     ///
+    /// # Errors
+    ///
+    /// Returns the same possible errors as [`create_application_command`],
+    /// checking every command.
+    ///
     /// [`create_application_command`]: Self::create_application_command
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
@@ -1086,13 +1091,71 @@ impl GuildId {
 
         f(&mut array);
 
-        http.as_ref().create_guild_application_commands(self.0, &Value::Array(array.0)).await
+        let commands = ApplicationCommand::validate_application_commands(array)?;
+        http.as_ref().create_guild_application_commands(self.0, &commands).await
+    }
+
+    /// Replaces all guild specific [`ApplicationCommand`]s of the application
+    /// with the given `commands`.
+    ///
+    /// Existing commands which are not in `commands` are deleted, and existing
+    /// commands with the same name are updated in place, keeping their Ids.
+    /// This is the recommended way to register guild commands at startup, as
+    /// creating them one at a time will create duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{ApplicationId, GuildId};
+    /// use serenity::builder::CreateApplicationCommand;
+    ///
+    /// # async fn run(http: &Http) -> serenity::Result<()> {
+    /// let mut ping = CreateApplicationCommand::default();
+    /// ping.name("ping").description("A simple ping command");
+    ///
+    /// let commands = GuildId(81384788765712384)
+    ///     .bulk_overwrite_application_commands(http, ApplicationId(7), vec![ping])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same possible errors as [`create_global_application_command`].
+    ///
+    /// [`ApplicationCommand`]: crate::model::interactions::ApplicationCommand
+    /// [`create_global_application_command`]: crate::model::interactions::ApplicationCommand::create_global_application_command
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub async fn bulk_overwrite_application_commands(
+        &self,
+        http: impl AsRef<Http>,
+        application_id: ApplicationId,
+        commands: Vec<CreateApplicationCommand>,
+    ) -> Result<Vec<ApplicationCommand>> {
+        let mut array = CreateApplicationCommands::default();
+        array.set_application_commands(commands);
+
+        let commands = ApplicationCommand::validate_application_commands(array)?;
+        http.as_ref()
+            .bulk_overwrite_guild_application_commands(application_id.0, self.0, &commands)
+            .await
     }
 
     /// Creates a guild specific [`ApplicationCommandPermission`].
     ///
     /// **Note**: It will update instantly.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyPermissions`] if more than 10
+    /// permissions are set.
+    ///
+    /// May also return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
     /// [`ApplicationCommandPermission`]: crate::model::interactions::ApplicationCommandPermission
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
@@ -1109,6 +1172,7 @@ impl GuildId {
     {
         let mut map = CreateApplicationCommandPermissionsData::default();
         f(&mut map);
+        map.check_permissions_len()?;
 
         http.as_ref()
             .edit_guild_application_command_permissions(
@@ -1122,6 +1186,14 @@ impl GuildId {
     /// Same as [`create_application_command_permission`] but allows to create
     /// more than one permission per call.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyPermissions`] if a command has more
+    /// than 10 permissions.
+    ///
+    /// May also return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
     /// [`create_application_command_permission`]: Self::create_application_command_permission
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
@@ -1137,6 +1209,7 @@ impl GuildId {
     {
         let mut map = CreateApplicationCommandsPermissions::default();
         f(&mut map);
+        map.check_permissions_len()?;
 
         http.as_ref()
             .edit_guild_application_commands_permissions(self.0, &Value::Array(map.0))
@@ -1165,6 +1238,13 @@ impl GuildId {
     }
 
     /// Edit guild application command by its Id.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same possible errors as [`create_application_command`],
+    /// except that the name and description may be left out.
+    ///
+    /// [`create_application_command`]: Self::create_application_command
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
     pub async fn edit_application_command<F>(
@@ -1176,7 +1256,7 @@ impl GuildId {
     where
         F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand,
     {
-        let map = ApplicationCommand::build_application_command(f);
+        let map = ApplicationCommand::build_application_command(f, true)?;
         http.as_ref()
             .edit_guild_application_command(self.0.into(), command_id.into(), &Value::Object(map))
             .await
@@ -154,6 +154,18 @@ pub struct CommandId(pub u64);
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct CommandPermissionId(pub u64);
 
+/// An identifier for a [`Team`][super::team::Team].
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct TeamId(pub u64);
+
+/// An identifier for an entitlement.
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct EntitlementId(pub u64);
+
+/// An identifier for a SKU.
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct SkuId(pub u64);
+
 id_u64! {
     AttachmentId;
     ApplicationId;
@@ -171,4 +183,7 @@ id_u64! {
     InteractionId;
     CommandId;
     CommandPermissionId;
+    TeamId;
+    EntitlementId;
+    SkuId;
 }
@@ -0,0 +1,189 @@
+//! Logging interactions as structured records.
+//!
+//! An [`InteractionAuditLogger`] writes one JSON object per line (NDJSON) for
+//! every logged [`Interaction`], which can be processed by tools such as `jq`.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::sync::Mutex;
+
+use super::{ApplicationCommandInteractionDataOption, Interaction};
+use crate::internal::prelude::*;
+use crate::model::id::{GuildId, InteractionId, UserId};
+
+/// A single line written by an [`InteractionAuditLogger`].
+#[derive(Serialize)]
+struct AuditRecord<'a> {
+    timestamp: DateTime<Utc>,
+    interaction_id: InteractionId,
+    guild_id: Option<GuildId>,
+    user_id: Option<UserId>,
+    command_name: Option<&'a str>,
+    options_summary: String,
+}
+
+/// Writes a structured record of every logged [`Interaction`] to a sink.
+///
+/// Each record is a JSON object on its own line, holding the `timestamp` it
+/// was logged at, the `interaction_id`, the `guild_id` and `user_id` of where
+/// and by whom it was triggered, the `command_name` and an `options_summary`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::model::interactions::Interaction;
+/// use serenity::model::interactions::audit::{file_sink, InteractionAuditLogger};
+///
+/// # async fn run(interaction: Interaction) -> serenity::Result<()> {
+/// let logger = InteractionAuditLogger::new(file_sink("interactions.ndjson").await?);
+///
+/// logger.log(&interaction).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct InteractionAuditLogger<W> {
+    sink: Mutex<W>,
+}
+
+impl<W: AsyncWrite + Unpin + Send> InteractionAuditLogger<W> {
+    /// Creates a logger writing to the given sink.
+    pub fn new(sink: W) -> Self {
+        Self {
+            sink: Mutex::new(sink),
+        }
+    }
+
+    /// Writes a record of the interaction to the sink.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if writing to the sink failed.
+    ///
+    /// [`Error::Io`]: crate::error::Error::Io
+    pub async fn log(&self, interaction: &Interaction) -> Result<()> {
+        let user_id = match (&interaction.member, &interaction.user) {
+            (Some(member), _) => Some(member.user.id),
+            (None, Some(user)) => Some(user.id),
+            (None, None) => None,
+        };
+
+        let record = AuditRecord {
+            timestamp: Utc::now(),
+            interaction_id: interaction.id,
+            guild_id: interaction.guild_id,
+            user_id,
+            command_name: interaction.data.as_ref().map(|data| data.name.as_str()),
+            options_summary: interaction
+                .data
+                .as_ref()
+                .map(|data| summarize_options(&data.options))
+                .unwrap_or_default(),
+        };
+
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+
+        let mut sink = self.sink.lock().await;
+        sink.write_all(&line).await?;
+        sink.flush().await?;
+
+        Ok(())
+    }
+
+    /// Consumes the logger, returning the sink.
+    pub fn into_inner(self) -> W {
+        self.sink.into_inner()
+    }
+}
+
+/// Opens the file at the given path for appending records to, creating it if
+/// it does not exist.
+///
+/// # Errors
+///
+/// Returns an [`Error::Io`] if the file could not be opened.
+///
+/// [`Error::Io`]: crate::error::Error::Io
+pub async fn file_sink(path: impl AsRef<Path>) -> Result<File> {
+    let file = OpenOptions::new().create(true).append(true).open(path).await?;
+
+    Ok(file)
+}
+
+/// Summarizes options as space-separated `name=value` pairs, with the names of
+/// sub-commands and sub-command groups preceding their options.
+fn summarize_options(options: &[ApplicationCommandInteractionDataOption]) -> String {
+    fn summarize(options: &[ApplicationCommandInteractionDataOption], parts: &mut Vec<String>) {
+        for option in options {
+            match &option.value {
+                Some(Value::String(value)) => parts.push(format!("{}={}", option.name, value)),
+                Some(value) => parts.push(format!("{}={}", option.name, value)),
+                None => {
+                    parts.push(option.name.clone());
+                    summarize(&option.options, parts);
+                },
+            }
+        }
+    }
+
+    let mut parts = Vec::new();
+    summarize(options, &mut parts);
+
+    parts.join(" ")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_log_line() {
+        let interaction: Interaction = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 2,
+            "data": {
+                "id": "3",
+                "name": "tag",
+                "options": [{
+                    "name": "get",
+                    "type": 1,
+                    "options": [
+                        {"name": "name", "type": 3, "value": "rust"},
+                        {"name": "limit", "type": 4, "value": 5},
+                    ],
+                }],
+            },
+            "user": {
+                "id": "4",
+                "username": "user",
+                "discriminator": "0001",
+                "avatar": null,
+            },
+            "token": "token",
+            "version": 1,
+            "locale": "en-US",
+        }))
+        .unwrap();
+
+        let logger = InteractionAuditLogger::new(Vec::new());
+        logger.log(&interaction).await.unwrap();
+
+        let output = logger.into_inner();
+        assert_eq!(output.last(), Some(&b'\n'));
+
+        let record: Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(record["interaction_id"], 1);
+        assert_eq!(record["guild_id"], Value::Null);
+        assert_eq!(record["user_id"], 4);
+        assert_eq!(record["command_name"], "tag");
+        assert_eq!(record["options_summary"], "get name=rust limit=5");
+    }
+}
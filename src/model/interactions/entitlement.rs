@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+
+use crate::model::id::{ApplicationId, EntitlementId, GuildId, SkuId, UserId};
+
+/// Access of a user or guild to a premium offering of an application.
+///
+/// [Discord docs](https://discord.com/developers/docs/monetization/entitlements#entitlement-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Entitlement {
+    /// The Id of the entitlement.
+    pub id: EntitlementId,
+    /// The Id of the SKU the entitlement grants access to.
+    pub sku_id: SkuId,
+    /// The Id of the application the SKU belongs to.
+    pub application_id: ApplicationId,
+    /// The Id of the user granted access, if the entitlement is for a user.
+    #[serde(default)]
+    pub user_id: Option<UserId>,
+    /// The Id of the guild granted access, if the entitlement is for a guild.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+    /// When the entitlement starts, if it is time-limited.
+    #[serde(default)]
+    pub starts_at: Option<DateTime<Utc>>,
+    /// When the entitlement ends, if it is time-limited.
+    #[serde(default)]
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Whether the entitlement was deleted.
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+impl Entitlement {
+    /// Whether the entitlement currently grants access, that is it was not
+    /// deleted and the current time is within its start and end dates.
+    pub fn is_active(&self) -> bool {
+        let now = Utc::now();
+
+        let started = match self.starts_at {
+            Some(starts_at) => starts_at <= now,
+            None => true,
+        };
+
+        let ended = match self.ends_at {
+            Some(ends_at) => ends_at <= now,
+            None => false,
+        };
+
+        !self.deleted && started && !ended
+    }
+}
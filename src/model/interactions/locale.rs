@@ -0,0 +1,165 @@
+use std::error::Error as StdError;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde::ser::{Serialize, Serializer};
+
+use crate::internal::prelude::*;
+
+macro_rules! locale_codes {
+    ($($(#[$attr:meta])* $variant:ident => $code:literal,)*) => {
+        /// A locale supported by Discord, such as for the localizations of an
+        /// [`ApplicationCommand`].
+        ///
+        /// It is formatted and parsed as the code Discord uses for the locale,
+        /// such as `en-US` or `fr`.
+        ///
+        /// [`ApplicationCommand`]: super::ApplicationCommand
+        #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+        #[non_exhaustive]
+        pub enum LocaleCode {
+            $($(#[$attr])* $variant,)*
+        }
+
+        impl LocaleCode {
+            /// Gets all the locales supported by Discord.
+            pub fn all() -> &'static [LocaleCode] {
+                &[$(LocaleCode::$variant,)*]
+            }
+
+            /// Gets the code Discord uses for the locale.
+            pub fn code(self) -> &'static str {
+                match self {
+                    $(LocaleCode::$variant => $code,)*
+                }
+            }
+        }
+
+        impl FromStr for LocaleCode {
+            type Err = LocaleCodeParseError;
+
+            fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+                match s {
+                    $($code => Ok(LocaleCode::$variant),)*
+                    _ => Err(LocaleCodeParseError(s.to_string())),
+                }
+            }
+        }
+    };
+}
+
+locale_codes! {
+    /// Indonesian.
+    Id => "id",
+    /// Danish.
+    Da => "da",
+    /// German.
+    De => "de",
+    /// English, UK.
+    EnGB => "en-GB",
+    /// English, US.
+    EnUS => "en-US",
+    /// Spanish.
+    EsES => "es-ES",
+    /// Spanish, LATAM.
+    Es419 => "es-419",
+    /// French.
+    Fr => "fr",
+    /// Croatian.
+    Hr => "hr",
+    /// Italian.
+    It => "it",
+    /// Lithuanian.
+    Lt => "lt",
+    /// Hungarian.
+    Hu => "hu",
+    /// Dutch.
+    Nl => "nl",
+    /// Norwegian.
+    No => "no",
+    /// Polish.
+    Pl => "pl",
+    /// Portuguese, Brazilian.
+    PtBR => "pt-BR",
+    /// Romanian.
+    Ro => "ro",
+    /// Finnish.
+    Fi => "fi",
+    /// Swedish.
+    SvSE => "sv-SE",
+    /// Vietnamese.
+    Vi => "vi",
+    /// Turkish.
+    Tr => "tr",
+    /// Czech.
+    Cs => "cs",
+    /// Greek.
+    El => "el",
+    /// Bulgarian.
+    Bg => "bg",
+    /// Russian.
+    Ru => "ru",
+    /// Ukrainian.
+    Uk => "uk",
+    /// Hindi.
+    Hi => "hi",
+    /// Thai.
+    Th => "th",
+    /// Chinese, China.
+    ZhCN => "zh-CN",
+    /// Japanese.
+    Ja => "ja",
+    /// Chinese, Taiwan.
+    ZhTW => "zh-TW",
+    /// Korean.
+    Ko => "ko",
+}
+
+impl fmt::Display for LocaleCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for LocaleCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(DeError::custom)
+    }
+}
+
+impl Serialize for LocaleCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+/// An error returned when parsing a [`LocaleCode`] which is not supported by
+/// Discord.
+///
+/// The string that failed to parse is provided.
+#[derive(Clone, Debug)]
+pub struct LocaleCodeParseError(pub String);
+
+impl fmt::Display for LocaleCodeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown locale: {}", self.0)
+    }
+}
+
+impl StdError for LocaleCodeParseError {}
+
+#[cfg(test)]
+mod test {
+    use super::LocaleCode;
+
+    #[test]
+    fn test_locale_code_round_trip() {
+        for locale in LocaleCode::all() {
+            assert_eq!(locale.to_string().parse::<LocaleCode>().ok(), Some(*locale));
+        }
+
+        assert_eq!(LocaleCode::EnUS.to_string(), "en-US");
+        assert!("en-us".parse::<LocaleCode>().is_err());
+    }
+}
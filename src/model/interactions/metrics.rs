@@ -0,0 +1,150 @@
+//! Recording metrics about handled interactions.
+//!
+//! A [`MetricsRecorder`] receives the command name, guild, duration and outcome
+//! of every command handled through a [`MetricsMiddleware`], which is enough
+//! to report invocation counts, latency histograms and error rates to a
+//! monitoring system such as Prometheus or statsd.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tracing::info;
+
+use super::Interaction;
+use crate::internal::prelude::*;
+use crate::model::id::GuildId;
+
+/// Receives metrics about handled application commands.
+pub trait MetricsRecorder: Send + Sync {
+    /// Records that a command was handled.
+    ///
+    /// The `duration` is the time the handler took, and `success` is whether
+    /// it returned [`Ok`].
+    fn record_invocation(
+        &self,
+        command: &str,
+        guild_id: Option<GuildId>,
+        duration: Duration,
+        success: bool,
+    );
+}
+
+/// A [`MetricsRecorder`] which emits a [`tracing`] event for every invocation.
+///
+/// [`tracing`]: https://docs.rs/tracing
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LoggingMetricsRecorder;
+
+impl MetricsRecorder for LoggingMetricsRecorder {
+    fn record_invocation(
+        &self,
+        command: &str,
+        guild_id: Option<GuildId>,
+        duration: Duration,
+        success: bool,
+    ) {
+        info!(
+            command,
+            guild_id = ?guild_id,
+            duration_ms = duration.as_millis() as u64,
+            success,
+            "Handled application command"
+        );
+    }
+}
+
+/// Times interaction handlers and reports them to a [`MetricsRecorder`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::model::interactions::Interaction;
+/// use serenity::model::interactions::metrics::{LoggingMetricsRecorder, MetricsMiddleware};
+///
+/// # async fn handle(interaction: &Interaction) -> serenity::Result<()> { Ok(()) }
+/// # async fn run(interaction: Interaction) -> serenity::Result<()> {
+/// let metrics = MetricsMiddleware::new(LoggingMetricsRecorder);
+///
+/// metrics.run(&interaction, handle(&interaction)).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MetricsMiddleware(pub Arc<dyn MetricsRecorder>);
+
+impl MetricsMiddleware {
+    /// Creates a middleware reporting to the given recorder.
+    pub fn new(recorder: impl MetricsRecorder + 'static) -> Self {
+        Self(Arc::new(recorder))
+    }
+
+    /// Runs the handler of an interaction, recording its duration and
+    /// outcome.
+    ///
+    /// **Note**: Only interactions carrying command data are recorded, other
+    /// interactions are handled as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the handler, if it failed.
+    pub async fn run<F, T, E>(&self, interaction: &Interaction, handler: F) -> StdResult<T, E>
+    where
+        F: Future<Output = StdResult<T, E>>,
+    {
+        let start = Instant::now();
+        let result = handler.await;
+
+        if let Some(data) = &interaction.data {
+            self.0.record_invocation(
+                &data.name,
+                interaction.guild_id,
+                start.elapsed(),
+                result.is_ok(),
+            );
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::model::interactions::testing::InteractionBuilder;
+
+    #[derive(Default)]
+    struct Recorded(Mutex<Vec<(String, Option<GuildId>, bool)>>);
+
+    impl MetricsRecorder for Recorded {
+        fn record_invocation(
+            &self,
+            command: &str,
+            guild_id: Option<GuildId>,
+            _: Duration,
+            ok: bool,
+        ) {
+            if let Ok(mut recorded) = self.0.lock() {
+                recorded.push((command.to_string(), guild_id, ok));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metrics_middleware() {
+        let recorder = Arc::new(Recorded::default());
+        let metrics = MetricsMiddleware(recorder.clone());
+        let interaction = InteractionBuilder::default().guild(1, 2).command("ping", 3).build();
+
+        assert!(metrics.run(&interaction, async { Ok::<_, ()>(()) }).await.is_ok());
+        assert!(metrics.run(&interaction, async { Err::<(), _>(()) }).await.is_err());
+
+        let recorded = recorder.0.lock().map(|r| r.clone()).unwrap_or_default();
+        assert_eq!(recorded, vec![
+            ("ping".to_string(), Some(GuildId(1)), true),
+            ("ping".to_string(), Some(GuildId(1)), false),
+        ]);
+    }
+}
@@ -0,0 +1,4644 @@
+//! Interactions information-related models.
+
+pub mod audit;
+mod entitlement;
+mod locale;
+pub mod metrics;
+pub mod pagination;
+mod poll;
+pub mod snapshot;
+mod sku;
+#[cfg(any(test, feature = "testing"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
+
+pub use self::entitlement::*;
+pub use self::locale::*;
+pub use self::poll::*;
+pub use self::sku::*;
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use bitflags::bitflags;
+use chrono::{DateTime, Utc};
+use serde::de::{Deserialize, Deserializer, Error as DeError};
+use serde::ser::{Serialize, Serializer};
+use serde_json::{json, Map, Number, Value};
+use tracing::warn;
+
+use super::prelude::*;
+use crate::builder::{
+    CreateActionRow,
+    CreateApplicationCommand,
+    CreateApplicationCommandPermissionsData,
+    CreateApplicationCommands,
+    CreateApplicationCommandsPermissions,
+    CreateInteractionResponse,
+    CreateInteractionResponseData,
+    CreateInteractionResponseFollowup,
+    CreateResponse,
+    EditInteractionResponse,
+};
+#[cfg(feature = "cache")]
+use crate::cache::Cache;
+use crate::http::Http;
+use crate::internal::prelude::*;
+use crate::utils;
+
+/// Information about an interaction.
+///
+/// An interaction is sent when a user invokes a slash command and is the same
+/// for slash commands and other future interaction types.
+#[derive(Clone, Debug, Serialize)]
+#[non_exhaustive]
+pub struct Interaction {
+    /// Id of the interaction.
+    pub id: InteractionId,
+    /// Id of the application this interaction is for.
+    pub application_id: ApplicationId,
+    /// The type of interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The data of the command which was triggered, if there is one.
+    ///
+    /// **Note**: It is always present if the interaction [`kind`] is
+    /// [`ApplicationCommand`].
+    ///
+    /// [`ApplicationCommand`]: self::InteractionType::ApplicationCommand
+    /// [`kind`]: Interaction::kind
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<ApplicationCommandInteractionData>,
+    /// The data of the message component which was used, if there is one.
+    ///
+    /// **Note**: It is always present if the interaction [`kind`] is
+    /// [`MessageComponent`].
+    ///
+    /// [`MessageComponent`]: self::InteractionType::MessageComponent
+    /// [`kind`]: Interaction::kind
+    #[serde(rename = "data", skip_serializing_if = "Option::is_none")]
+    pub component_data: Option<MessageComponentInteractionData>,
+    /// The data of the modal which was submitted, if there is one.
+    ///
+    /// **Note**: It is always present if the interaction [`kind`] is
+    /// [`ModalSubmit`].
+    ///
+    /// [`ModalSubmit`]: self::InteractionType::ModalSubmit
+    /// [`kind`]: Interaction::kind
+    #[serde(rename = "data", skip_serializing_if = "Option::is_none")]
+    pub modal_data: Option<ModalSubmitInteractionData>,
+    /// The guild Id this interaction was sent from, if there is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// The channel Id this interaction was sent from, if there is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    /// The `member` data for the invoking user.
+    ///
+    /// **Note**: It is only present if the interaction is triggered in a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<Member>,
+    /// The `user` object for the invoking user.
+    ///
+    /// It is only present if the interaction is triggered in DM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// A continuation token for responding to the interaction.
+    ///
+    /// **Note**: The token is redacted when formatted, use
+    /// [`SafeToken::expose`] to access it.
+    pub token: SafeToken,
+    /// Always `1`.
+    pub version: u8,
+    /// The selected language of the invoking user.
+    ///
+    /// **Note**: It is empty for [`Ping`] interactions, which are sent without
+    /// a locale.
+    ///
+    /// [`Ping`]: self::InteractionType::Ping
+    pub locale: String,
+    /// The guild's preferred locale.
+    ///
+    /// **Note**: It is only present if the interaction is triggered in a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_locale: Option<String>,
+    /// The permissions of the bot in the channel the interaction was sent
+    /// from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_permissions: Option<Permissions>,
+    /// The entitlements of the invoking user or guild to the premium
+    /// offerings of the application.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub entitlements: Vec<Entitlement>,
+    /// The installations which authorized the interaction, mapped to the Id
+    /// of the guild or user the application is installed to.
+    ///
+    /// **Note**: For guild installs, the Id is `0` if the interaction was
+    /// triggered in direct messages with the bot.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub authorizing_integration_owners: HashMap<ApplicationIntegrationType, u64>,
+}
+
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        deserialize_interaction_with_guild_id(deserializer, None)
+    }
+}
+
+/// Deserializes an [`Interaction`], injecting the Id of the guild it was sent
+/// from into the objects that need it.
+///
+/// Discord omits the `guild_id` of the invoking [`Member`] and of the resolved
+/// [`Role`]s and [`PartialChannel`]s, as it is the same as the interaction's.
+/// These objects require it however, so it is injected into them before they
+/// are deserialized.
+///
+/// The [`Deserialize`] implementation of [`Interaction`] calls this with no
+/// `guild_id`, in which case the `guild_id` field of the payload is used. Pass
+/// a `guild_id` explicitly when deserializing a payload which lacks it, such
+/// as one stored without its `guild_id` or built by hand in tests. Injection
+/// is not needed for interactions sent in direct messages, where there is no
+/// member and resolved roles and channels do not belong to a guild.
+///
+/// # Errors
+///
+/// Returns an error if the payload is not a valid interaction.
+pub fn deserialize_interaction_with_guild_id<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    guild_id: Option<GuildId>,
+) -> StdResult<Interaction, D::Error> {
+    let mut map = JsonMap::deserialize(deserializer)?;
+
+    let guild_id = guild_id.or_else(|| {
+        map.get("guild_id")
+            .and_then(|x| x.as_str())
+            .and_then(|x| x.parse::<u64>().ok())
+            .map(GuildId)
+    });
+
+    if let Some(guild_id) = guild_id {
+        inject_guild_id(&mut map, guild_id);
+    }
+
+    Interaction::from_map(map)
+}
+
+fn inject_guild_id(map: &mut JsonMap, guild_id: GuildId) {
+    if !map.contains_key("guild_id") {
+        map.insert("guild_id".to_string(), Value::String(guild_id.to_string()));
+    }
+
+    if let Some(member) = map.get_mut("member").and_then(|x| x.as_object_mut()) {
+        member.insert("guild_id".to_string(), Value::Number(Number::from(guild_id.0)));
+    }
+
+    if let Some(resolved) = map.get_mut("data").and_then(|x| x.get_mut("resolved")) {
+        for key in &["roles", "channels"] {
+            if let Some(values) = resolved.get_mut(*key).and_then(|x| x.as_object_mut()) {
+                for value in values.values_mut().filter_map(|x| x.as_object_mut()) {
+                    value.insert("guild_id".to_string(), Value::String(guild_id.to_string()));
+                }
+            }
+        }
+    }
+}
+
+impl Interaction {
+    fn from_map<E: DeError>(mut map: JsonMap) -> StdResult<Self, E> {
+        let id = map
+            .remove("id")
+            .ok_or_else(|| DeError::custom("expected id"))
+            .and_then(InteractionId::deserialize)
+            .map_err(DeError::custom)?;
+
+        let application_id = map
+            .remove("application_id")
+            .ok_or_else(|| DeError::custom("expected application id"))
+            .and_then(ApplicationId::deserialize)
+            .map_err(DeError::custom)?;
+
+        let kind = map
+            .remove("type")
+            .ok_or_else(|| DeError::custom("expected type"))
+            .and_then(InteractionType::deserialize)
+            .map_err(DeError::custom)?;
+
+        let component_data = match kind {
+            InteractionType::MessageComponent => Some(
+                map.remove("data")
+                    .ok_or_else(|| DeError::custom("expected data"))
+                    .and_then(MessageComponentInteractionData::deserialize)
+                    .map_err(DeError::custom)?,
+            ),
+            _ => None,
+        };
+
+        let modal_data = match kind {
+            InteractionType::ModalSubmit => Some(
+                map.remove("data")
+                    .ok_or_else(|| DeError::custom("expected data"))
+                    .and_then(ModalSubmitInteractionData::deserialize)
+                    .map_err(DeError::custom)?,
+            ),
+            _ => None,
+        };
+
+        let data = match map.contains_key("data") {
+            true => Some(
+                map.remove("data")
+                    .ok_or_else(|| DeError::custom("expected data"))
+                    .and_then(ApplicationCommandInteractionData::deserialize)
+                    .map_err(DeError::custom)?,
+            ),
+            false => None,
+        };
+
+        let guild_id = match map.contains_key("guild_id") {
+            true => Some(
+                map.remove("guild_id")
+                    .ok_or_else(|| DeError::custom("expected guild_id"))
+                    .and_then(GuildId::deserialize)
+                    .map_err(DeError::custom)?,
+            ),
+            false => None,
+        };
+
+        let channel_id = match map.contains_key("channel_id") {
+            true => Some(
+                map.remove("channel_id")
+                    .ok_or_else(|| DeError::custom("expected channel_id"))
+                    .and_then(ChannelId::deserialize)
+                    .map_err(DeError::custom)?,
+            ),
+            false => None,
+        };
+
+        let member = match map.contains_key("member") {
+            true => Some(
+                map.remove("member")
+                    .ok_or_else(|| DeError::custom("expected member"))
+                    .and_then(Member::deserialize)
+                    .map_err(DeError::custom)?,
+            ),
+            false => None,
+        };
+
+        let user = match map.contains_key("user") {
+            true => Some(
+                map.remove("user")
+                    .ok_or_else(|| DeError::custom("expected user"))
+                    .and_then(User::deserialize)
+                    .map_err(DeError::custom)?,
+            ),
+            false => None,
+        };
+
+        let token = map
+            .remove("token")
+            .ok_or_else(|| DeError::custom("expected token"))
+            .and_then(SafeToken::deserialize)
+            .map_err(DeError::custom)?;
+
+        let version = map
+            .remove("version")
+            .ok_or_else(|| DeError::custom("expected version"))
+            .and_then(u8::deserialize)
+            .map_err(DeError::custom)?;
+
+        let locale = match kind {
+            InteractionType::Ping => String::new(),
+            _ => map
+                .remove("locale")
+                .ok_or_else(|| DeError::custom("expected locale"))
+                .and_then(String::deserialize)
+                .map_err(DeError::custom)?,
+        };
+
+        let guild_locale = match map.contains_key("guild_locale") {
+            true => map
+                .remove("guild_locale")
+                .ok_or_else(|| DeError::custom("expected guild_locale"))
+                .and_then(Option::<String>::deserialize)
+                .map_err(DeError::custom)?,
+            false => None,
+        };
+
+        let app_permissions = match map.contains_key("app_permissions") {
+            true => map
+                .remove("app_permissions")
+                .ok_or_else(|| DeError::custom("expected app_permissions"))
+                .and_then(Option::<Permissions>::deserialize)
+                .map_err(DeError::custom)?,
+            false => None,
+        };
+
+        let entitlements = match map.contains_key("entitlements") {
+            true => map
+                .remove("entitlements")
+                .ok_or_else(|| DeError::custom("expected entitlements"))
+                .and_then(Vec::<Entitlement>::deserialize)
+                .map_err(DeError::custom)?,
+            false => Vec::new(),
+        };
+
+        // Discord sends the Ids as strings, while they are serialized as
+        // numbers, so both are accepted.
+        #[derive(Deserialize)]
+        struct OwnerId(#[serde(deserialize_with = "deserialize_u64")] u64);
+
+        let owners = match map.contains_key("authorizing_integration_owners") {
+            true => map
+                .remove("authorizing_integration_owners")
+                .ok_or_else(|| DeError::custom("expected authorizing_integration_owners"))
+                .and_then(HashMap::<ApplicationIntegrationType, OwnerId>::deserialize)
+                .map_err(DeError::custom)?
+                .into_iter()
+                .map(|(kind, OwnerId(id))| (kind, id))
+                .collect(),
+            false => HashMap::new(),
+        };
+
+        Ok(Self {
+            id,
+            application_id,
+            kind,
+            data,
+            component_data,
+            modal_data,
+            guild_id,
+            channel_id,
+            member,
+            user,
+            token,
+            version,
+            locale,
+            guild_locale,
+            app_permissions,
+            entitlements,
+            authorizing_integration_owners: owners,
+        })
+    }
+}
+
+/// The token of an [`Interaction`], which is redacted when formatted.
+///
+/// Interaction tokens allow editing the responses of an interaction and
+/// sending followup messages, so they should not end up in logs. Both the
+/// [`Display`] and [`Debug`] implementations hide the token, use [`expose`] to
+/// access it.
+///
+/// **Note**: The token is serialized as-is.
+///
+/// [`Display`]: fmt::Display
+/// [`Debug`]: fmt::Debug
+/// [`expose`]: Self::expose
+#[derive(Clone, Eq, PartialEq)]
+pub struct SafeToken(Arc<str>);
+
+impl SafeToken {
+    /// Returns the raw token.
+    #[inline]
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SafeToken {
+    fn from(token: String) -> Self {
+        Self(token.into())
+    }
+}
+
+impl From<&str> for SafeToken {
+    fn from(token: &str) -> Self {
+        Self(token.into())
+    }
+}
+
+impl fmt::Display for SafeToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+impl fmt::Debug for SafeToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SafeToken(<redacted>)")
+    }
+}
+
+impl<'de> Deserialize<'de> for SafeToken {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Serialize for SafeToken {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+/// The type of an Interaction
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum InteractionType {
+    Ping = 1,
+    ApplicationCommand = 2,
+    MessageComponent = 3,
+    Autocomplete = 4,
+    ModalSubmit = 5,
+    Unknown = !0,
+}
+
+enum_number!(InteractionType {
+    Ping,
+    ApplicationCommand,
+    MessageComponent,
+    Autocomplete,
+    ModalSubmit
+});
+
+impl FromStr for InteractionType {
+    type Err = InteractionTypeParseError;
+
+    /// Parses the name of a variant, ignoring case.
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "ping" => Ok(InteractionType::Ping),
+            "applicationcommand" => Ok(InteractionType::ApplicationCommand),
+            "messagecomponent" => Ok(InteractionType::MessageComponent),
+            "autocomplete" => Ok(InteractionType::Autocomplete),
+            "modalsubmit" => Ok(InteractionType::ModalSubmit),
+            "unknown" => Ok(InteractionType::Unknown),
+            _ => Err(InteractionTypeParseError(s.to_string())),
+        }
+    }
+}
+
+/// An error returned when parsing an invalid [`InteractionType`].
+///
+/// The string that failed to parse is provided.
+#[derive(Clone, Debug)]
+pub struct InteractionTypeParseError(pub String);
+
+impl fmt::Display for InteractionTypeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown interaction type: {}", self.0)
+    }
+}
+
+impl std::error::Error for InteractionTypeParseError {}
+
+/// The data of a message component interaction payload.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MessageComponentInteractionData {
+    /// The custom Id of the component, set when the component was created.
+    pub custom_id: String,
+    /// The type of the component.
+    pub component_type: ComponentType,
+    /// The values chosen by the user.
+    ///
+    /// **Note**: It is only present if the component is a
+    /// [`ComponentType::SelectMenu`].
+    #[serde(default)]
+    pub values: Vec<String>,
+}
+
+impl MessageComponentInteractionData {
+    /// Whether the component is a [`ComponentType::Button`].
+    #[inline]
+    pub fn is_button(&self) -> bool {
+        self.component_type == ComponentType::Button
+    }
+
+    /// Whether the component is a [`ComponentType::SelectMenu`].
+    #[inline]
+    pub fn is_select_menu(&self) -> bool {
+        self.component_type == ComponentType::SelectMenu
+    }
+
+    /// Gets the custom Id of the component if it is a button, or `None`
+    /// otherwise.
+    pub fn button_custom_id(&self) -> Option<&str> {
+        if self.is_button() {
+            Some(&self.custom_id)
+        } else {
+            None
+        }
+    }
+
+    /// Gets the values chosen by the user if the component is a select menu,
+    /// or `None` otherwise.
+    pub fn selected_values(&self) -> Option<&[String]> {
+        if self.is_select_menu() {
+            Some(&self.values)
+        } else {
+            None
+        }
+    }
+
+    /// Gets the data of the select menu, or `None` if the component is not a
+    /// select menu.
+    pub fn select_menu(&self) -> Option<SelectMenuInteractionData> {
+        self.selected_values().map(|values| SelectMenuInteractionData {
+            custom_id: self.custom_id.clone(),
+            values: values.to_vec(),
+        })
+    }
+}
+
+/// The data of a select menu interaction.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct SelectMenuInteractionData {
+    /// The custom Id of the select menu, set when it was created.
+    pub custom_id: String,
+    /// The values of the options chosen by the user.
+    #[serde(default)]
+    pub values: Vec<String>,
+}
+
+/// An option of a select menu.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct SelectMenuOption {
+    /// The text shown for the option.
+    pub label: String,
+    /// The value sent in the interaction when the option is selected.
+    pub value: String,
+    /// The text shown below the label of the option.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The emoji shown next to the option.
+    #[serde(default)]
+    pub emoji: Option<ReactionType>,
+    /// Whether the option is selected when the message is first shown.
+    #[serde(default)]
+    pub default: bool,
+}
+
+/// The type of a message component.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ComponentType {
+    ActionRow = 1,
+    Button = 2,
+    SelectMenu = 3,
+    InputText = 4,
+    Unknown = !0,
+}
+
+enum_number!(ComponentType {
+    ActionRow,
+    Button,
+    SelectMenu,
+    InputText
+});
+
+/// The style of a button.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#button-object-button-styles).
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ButtonStyle {
+    /// A blurple button.
+    Primary = 1,
+    /// A grey button.
+    Secondary = 2,
+    /// A green button.
+    Success = 3,
+    /// A red button.
+    Danger = 4,
+    /// A grey button which opens a URL.
+    Link = 5,
+    Unknown = !0,
+}
+
+enum_number!(ButtonStyle {
+    Primary,
+    Secondary,
+    Success,
+    Danger,
+    Link
+});
+
+/// The style of a text input.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum TextInputStyle {
+    /// A single-line input.
+    Short = 1,
+    /// A multi-line input.
+    Paragraph = 2,
+    Unknown = !0,
+}
+
+enum_number!(TextInputStyle {
+    Short,
+    Paragraph
+});
+
+/// The data of a modal submit interaction payload.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ModalSubmitInteractionData {
+    /// The custom Id of the modal, set when the modal was created.
+    pub custom_id: String,
+    /// The action rows of the modal, holding the submitted values.
+    #[serde(default)]
+    pub components: Vec<ModalSubmitActionRow>,
+}
+
+impl ModalSubmitInteractionData {
+    /// Gets the values entered by the user, keyed by the custom Id of their
+    /// text input.
+    pub fn fields(&self) -> HashMap<&str, &str> {
+        self.components
+            .iter()
+            .flat_map(|row| &row.components)
+            .map(|input| (input.custom_id.as_str(), input.value.as_str()))
+            .collect()
+    }
+
+    /// Gets the value entered by the user in the text input with the given
+    /// custom Id.
+    pub fn get_field(&self, custom_id: &str) -> Option<&str> {
+        self.components
+            .iter()
+            .flat_map(|row| &row.components)
+            .find(|input| input.custom_id == custom_id)
+            .map(|input| input.value.as_str())
+    }
+}
+
+/// An action row of a submitted modal.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ModalSubmitActionRow {
+    /// The type of the component, always [`ComponentType::ActionRow`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The components of the action row.
+    #[serde(default)]
+    pub components: Vec<ModalSubmitComponent>,
+}
+
+/// A component of a submitted modal.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ModalSubmitComponent {
+    /// The type of the component.
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The custom Id of the component, set when the modal was created.
+    pub custom_id: String,
+    /// The value entered by the user.
+    #[serde(default)]
+    pub value: String,
+}
+
+/// The command data payload.
+#[derive(Clone, Debug, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandInteractionData {
+    /// The Id of the invoked command.
+    pub id: CommandId,
+    /// The name of the invoked command.
+    pub name: String,
+    #[serde(default)]
+    /// The parameters and the given values.
+    pub options: Vec<ApplicationCommandInteractionDataOption>,
+    #[serde(default)]
+    /// The converted objects from the given options.
+    pub resolved: ApplicationCommandInteractionDataResolved,
+}
+
+impl<'de> Deserialize<'de> for ApplicationCommandInteractionData {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+
+        let name = map
+            .remove("name")
+            .ok_or_else(|| DeError::custom("expected value"))
+            .and_then(String::deserialize)
+            .map_err(DeError::custom)?;
+
+        let id = map
+            .remove("id")
+            .ok_or_else(|| DeError::custom("expected value"))
+            .and_then(CommandId::deserialize)
+            .map_err(DeError::custom)?;
+
+        let resolved = match map.contains_key("resolved") {
+            true => map
+                .remove("resolved")
+                .ok_or_else(|| DeError::custom("expected resolved"))
+                .and_then(ApplicationCommandInteractionDataResolved::deserialize)
+                .map_err(DeError::custom)?,
+            false => ApplicationCommandInteractionDataResolved::default(),
+        };
+
+        let options = match map.contains_key("options") {
+            true => map
+                .remove("options")
+                .ok_or_else(|| DeError::custom("expected options"))
+                .and_then(|deserializer| deserialize_options_with_resolved(deserializer, &resolved))
+                .map_err(DeError::custom)?,
+            false => vec![],
+        };
+
+        Ok(Self {
+            name,
+            id,
+            options,
+            resolved,
+        })
+    }
+}
+
+/// The resolved data of a command data interaction payload.
+/// It contains the objects of [`ApplicationCommandInteractionDataOption`]s.
+#[derive(Clone, Debug, Serialize, Default)]
+#[non_exhaustive]
+pub struct ApplicationCommandInteractionDataResolved {
+    pub users: ResolvedMap<UserId, User>,
+    pub members: ResolvedMap<UserId, PartialMember>,
+    pub roles: ResolvedMap<RoleId, Role>,
+    pub channels: ResolvedMap<ChannelId, PartialChannel>,
+    pub attachments: ResolvedMap<AttachmentId, Attachment>,
+    /// The messages targeted by message context menu commands.
+    pub messages: ResolvedMap<MessageId, Message>,
+}
+
+/// The map type of the fields of [`ApplicationCommandInteractionDataResolved`].
+///
+/// It is an `IndexMap`, which iterates in the order Discord sent the entries
+/// in, if the `preserve_order` feature is enabled, and a [`HashMap`] otherwise.
+#[cfg(feature = "preserve_order")]
+pub type ResolvedMap<K, V> = indexmap::IndexMap<K, V>;
+/// The map type of the fields of [`ApplicationCommandInteractionDataResolved`].
+///
+/// It is an `IndexMap`, which iterates in the order Discord sent the entries
+/// in, if the `preserve_order` feature is enabled, and a [`HashMap`] otherwise.
+#[cfg(not(feature = "preserve_order"))]
+pub type ResolvedMap<K, V> = HashMap<K, V>;
+
+impl<'de> Deserialize<'de> for ApplicationCommandInteractionDataResolved {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+
+        let members = match map.contains_key("members") {
+            true => map
+                .remove("members")
+                .ok_or_else(|| DeError::custom("expected members"))
+                .and_then(deserialize_partial_members_map)
+                .map_err(DeError::custom)?,
+            false => ResolvedMap::new(),
+        };
+
+        let users = match map.contains_key("users") {
+            true => map
+                .remove("users")
+                .ok_or_else(|| DeError::custom("expected users"))
+                .and_then(deserialize_users)
+                .map_err(DeError::custom)?,
+            false => ResolvedMap::new(),
+        };
+
+        let roles = match map.contains_key("roles") {
+            true => map
+                .remove("roles")
+                .ok_or_else(|| DeError::custom("expected roles"))
+                .and_then(deserialize_roles_map)
+                .map_err(DeError::custom)?,
+            false => ResolvedMap::new(),
+        };
+
+        let channels = match map.contains_key("channels") {
+            true => map
+                .remove("channels")
+                .ok_or_else(|| DeError::custom("expected chanels"))
+                .and_then(deserialize_channels_map)
+                .map_err(DeError::custom)?,
+            false => ResolvedMap::new(),
+        };
+
+        let attachments = match map.contains_key("attachments") {
+            true => map
+                .remove("attachments")
+                .ok_or_else(|| DeError::custom("expected attachments"))
+                .and_then(deserialize_attachments_map)
+                .map_err(DeError::custom)?,
+            false => ResolvedMap::new(),
+        };
+
+        let messages = match map.contains_key("messages") {
+            true => map
+                .remove("messages")
+                .ok_or_else(|| DeError::custom("expected messages"))
+                .and_then(deserialize_messages_map)
+                .map_err(DeError::custom)?,
+            false => ResolvedMap::new(),
+        };
+
+        Ok(Self {
+            users,
+            members,
+            roles,
+            channels,
+            attachments,
+            messages,
+        })
+    }
+}
+
+/// A set of a parameter and a value from the user.
+///
+/// All options have names and an option can either be a parameter and input `value` or it can denote a sub-command or group, in which case it will contain a
+/// top-level key and another vector of `options`.
+///
+/// Their resolved objects can be found on [`ApplicationCommandInteractionData::resolved`].
+#[derive(Clone, Debug, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandInteractionDataOption {
+    /// The name of the parameter.
+    pub name: String,
+    /// The given value.
+    pub value: Option<Value>,
+    /// The value type.
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandOptionType,
+    /// The nested options.
+    ///
+    /// **Note**: It is only present if the option is
+    /// a group or a subcommand.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandInteractionDataOption>,
+    /// The resolved object of the given `value`, if there is one.
+    #[serde(default)]
+    pub resolved: Option<ApplicationCommandInteractionDataOptionValue>,
+    /// Whether this option is the one the user is currently typing in.
+    ///
+    /// **Note**: It can only be true for [`InteractionType::Autocomplete`]
+    /// interactions, in which case the `value` is the partial input as a
+    /// string and is not resolved.
+    #[serde(default)]
+    pub focused: bool,
+}
+
+impl<'de> Deserialize<'de> for ApplicationCommandInteractionDataOption {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+
+        let name = map
+            .remove("name")
+            .ok_or_else(|| DeError::custom("expected value"))
+            .and_then(String::deserialize)
+            .map_err(DeError::custom)?;
+
+        let value = match map.contains_key("value") {
+            true => Some(
+                map.remove("value")
+                    .ok_or_else(|| DeError::custom("expected value"))
+                    .and_then(Value::deserialize)
+                    .map_err(DeError::custom)?,
+            ),
+            false => None,
+        };
+
+        let kind = map
+            .remove("type")
+            .ok_or_else(|| DeError::custom("expected type"))
+            .and_then(ApplicationCommandOptionType::deserialize)
+            .map_err(DeError::custom)?;
+
+        let options = match map.contains_key("options") {
+            true => map
+                .remove("options")
+                .ok_or_else(|| DeError::custom("expected type"))
+                .and_then(deserialize_options)
+                .map_err(DeError::custom)?,
+            false => vec![],
+        };
+
+        let focused = match map.contains_key("focused") {
+            true => map
+                .remove("focused")
+                .ok_or_else(|| DeError::custom("expected focused"))
+                .and_then(bool::deserialize)
+                .map_err(DeError::custom)?,
+            false => false,
+        };
+
+        Ok(Self {
+            name,
+            value,
+            kind,
+            options,
+            resolved: None,
+            focused,
+        })
+    }
+}
+
+impl ApplicationCommandInteractionDataOption {
+    /// Gets the value of a [`String`] option.
+    ///
+    /// [`String`]: ApplicationCommandOptionType::String
+    pub fn as_string(&self) -> Option<&str> {
+        self.resolved.as_ref()?.as_str()
+    }
+
+    /// Gets the value of an [`Integer`] option.
+    ///
+    /// [`Integer`]: ApplicationCommandOptionType::Integer
+    pub fn as_i64(&self) -> Option<i64> {
+        self.resolved.as_ref()?.as_i64()
+    }
+
+    /// Gets the value of a [`Number`] option.
+    ///
+    /// [`Number`]: ApplicationCommandOptionType::Number
+    pub fn as_f64(&self) -> Option<f64> {
+        self.resolved.as_ref()?.as_f64()
+    }
+
+    /// Gets the value of a [`Boolean`] option.
+    ///
+    /// [`Boolean`]: ApplicationCommandOptionType::Boolean
+    pub fn as_bool(&self) -> Option<bool> {
+        self.resolved.as_ref()?.as_bool()
+    }
+
+    /// Gets the user of a [`User`] option, along with their member data if
+    /// the command was used in a guild.
+    ///
+    /// [`User`]: ApplicationCommandOptionType::User
+    pub fn as_user(&self) -> Option<(&User, Option<&PartialMember>)> {
+        self.resolved.as_ref()?.as_user()
+    }
+
+    /// Gets the role of a [`Role`] option.
+    ///
+    /// [`Role`]: ApplicationCommandOptionType::Role
+    pub fn as_role(&self) -> Option<&Role> {
+        self.resolved.as_ref()?.as_role()
+    }
+
+    /// Gets the channel of a [`Channel`] option.
+    ///
+    /// [`Channel`]: ApplicationCommandOptionType::Channel
+    pub fn as_channel(&self) -> Option<&PartialChannel> {
+        self.resolved.as_ref()?.as_channel()
+    }
+}
+
+/// The resolved value of an [`ApplicationCommandInteractionDataOption`].
+///
+/// It serializes to the bare inner value: strings, integers, numbers and
+/// booleans as their JSON equivalents, and users, channels, roles and
+/// attachments as their objects.
+/// A user's [`PartialMember`], if any, is stored under the `member` key of the
+/// user object.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ApplicationCommandInteractionDataOptionValue {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+    User(User, Option<PartialMember>),
+    Channel(PartialChannel),
+    Role(Role),
+    Number(f64),
+    Attachment(Attachment),
+    Message(Message),
+}
+
+impl ApplicationCommandInteractionDataOptionValue {
+    /// Gets the value of a [`String`] option.
+    ///
+    /// [`String`]: ApplicationCommandOptionType::String
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Gets the value of an [`Integer`] option.
+    ///
+    /// [`Integer`]: ApplicationCommandOptionType::Integer
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Gets the value of a [`Number`] option.
+    ///
+    /// [`Number`]: ApplicationCommandOptionType::Number
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Gets the value of a [`Number`] option.
+    ///
+    /// This is an alias of [`Self::as_number`], named after
+    /// [`Value::as_f64`].
+    ///
+    /// [`Number`]: ApplicationCommandOptionType::Number
+    #[inline]
+    pub fn as_f64(&self) -> Option<f64> {
+        self.as_number()
+    }
+
+    /// Gets the value of a [`Boolean`] option.
+    ///
+    /// [`Boolean`]: ApplicationCommandOptionType::Boolean
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Gets the user of a [`User`] option, along with their member data if
+    /// the command was used in a guild.
+    ///
+    /// [`User`]: ApplicationCommandOptionType::User
+    pub fn as_user(&self) -> Option<(&User, Option<&PartialMember>)> {
+        match self {
+            Self::User(user, member) => Some((user, member.as_ref())),
+            _ => None,
+        }
+    }
+
+    /// Gets the role of a [`Role`] option.
+    ///
+    /// [`Role`]: ApplicationCommandOptionType::Role
+    pub fn as_role(&self) -> Option<&Role> {
+        match self {
+            Self::Role(role) => Some(role),
+            _ => None,
+        }
+    }
+
+    /// Gets the channel of a [`Channel`] option.
+    ///
+    /// [`Channel`]: ApplicationCommandOptionType::Channel
+    pub fn as_channel(&self) -> Option<&PartialChannel> {
+        match self {
+            Self::Channel(channel) => Some(channel),
+            _ => None,
+        }
+    }
+
+    /// Gets the resolved message, if the value is one.
+    pub fn as_message(&self) -> Option<&Message> {
+        match self {
+            Self::Message(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for ApplicationCommandInteractionDataOptionValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct UserWithMember<'a> {
+            #[serde(flatten)]
+            user: &'a User,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            member: Option<&'a PartialMember>,
+        }
+
+        match self {
+            Self::String(s) => serializer.serialize_str(s),
+            Self::Integer(i) => serializer.serialize_i64(*i),
+            Self::Number(n) => serializer.serialize_f64(*n),
+            Self::Boolean(b) => serializer.serialize_bool(*b),
+            Self::User(user, member) => UserWithMember {
+                user,
+                member: member.as_ref(),
+            }
+            .serialize(serializer),
+            Self::Channel(channel) => channel.serialize(serializer),
+            Self::Role(role) => role.serialize(serializer),
+            Self::Attachment(attachment) => attachment.serialize(serializer),
+            Self::Message(message) => message.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ApplicationCommandInteractionDataOptionValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        match value {
+            Value::String(s) => Ok(Self::String(s)),
+            Value::Bool(b) => Ok(Self::Boolean(b)),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => Ok(Self::Integer(i)),
+                None => {
+                    n.as_f64().map(Self::Number).ok_or_else(|| DeError::custom("expected a number"))
+                },
+            },
+            Value::Object(mut map) => {
+                if map.contains_key("username") {
+                    let member = match map.remove("member") {
+                        Some(member) => {
+                            Some(PartialMember::deserialize(member).map_err(DeError::custom)?)
+                        },
+                        None => None,
+                    };
+                    let user = User::deserialize(Value::Object(map)).map_err(DeError::custom)?;
+
+                    Ok(Self::User(user, member))
+                } else if map.contains_key("filename") {
+                    Attachment::deserialize(Value::Object(map))
+                        .map(Self::Attachment)
+                        .map_err(DeError::custom)
+                } else if map.contains_key("position") {
+                    Role::deserialize(Value::Object(map)).map(Self::Role).map_err(DeError::custom)
+                } else if map.contains_key("author") {
+                    Message::deserialize(Value::Object(map))
+                        .map(Self::Message)
+                        .map_err(DeError::custom)
+                } else if map.contains_key("type") {
+                    PartialChannel::deserialize(Value::Object(map))
+                        .map(Self::Channel)
+                        .map_err(DeError::custom)
+                } else {
+                    Err(DeError::custom(
+                        "expected a user, channel, role, attachment or message object",
+                    ))
+                }
+            },
+            _ => Err(DeError::custom("expected a resolved option value")),
+        }
+    }
+}
+
+fn default_permission_value() -> bool {
+    true
+}
+
+fn default_command_type() -> ApplicationCommandType {
+    ApplicationCommandType::ChatInput
+}
+
+/// The base command model that belongs to an application.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommand {
+    /// The command Id.
+    pub id: CommandId,
+    /// The parent application Id.
+    pub application_id: ApplicationId,
+    /// The Id of the guild the command belongs to.
+    ///
+    /// **Note**: It is only present for guild commands.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// The command type.
+    #[serde(rename = "type", default = "self::default_command_type")]
+    pub kind: ApplicationCommandType,
+    /// The command name.
+    pub name: String,
+    /// The localized names of the command, keyed by locale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<HashMap<String, String>>,
+    /// The command name in the locale of the user who requested the command.
+    ///
+    /// **Note**: Discord only returns it when the locale is sent along with
+    /// the request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localized: Option<String>,
+    /// The command description.
+    ///
+    /// **Note**: It is empty for context menu commands.
+    #[serde(default)]
+    pub description: String,
+    /// The localized descriptions of the command, keyed by locale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<HashMap<String, String>>,
+    /// The command description in the locale of the user who requested the
+    /// command.
+    ///
+    /// **Note**: Discord only returns it when the locale is sent along with
+    /// the request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localized: Option<String>,
+    /// The parameters for the command.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+    /// Whether the command is enabled by default when
+    /// the application is added to a guild.
+    #[deprecated(note = "use `default_member_permissions` instead")]
+    #[serde(default = "self::default_permission_value")]
+    pub default_permission: bool,
+    /// The permissions a member needs to use the command by default.
+    ///
+    /// **Note**: `None` means that everyone can use the command, while
+    /// [`Permissions::empty`] means that only administrators can use it.
+    /// Guild administrators can override this for their guild.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_member_permissions: Option<Permissions>,
+    /// Whether the command can be used in direct messages.
+    ///
+    /// **Note**: It has no effect on guild commands, for which Discord omits
+    /// it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dm_permission: Option<bool>,
+    /// The installation contexts the command is available in.
+    ///
+    /// **Note**: An empty list means that Discord's default is used.
+    #[serde(default, deserialize_with = "deserialize_null_default")]
+    pub integration_types: Vec<ApplicationIntegrationType>,
+    /// The interaction contexts the command can be used in.
+    ///
+    /// **Note**: An empty list means that Discord's default is used.
+    #[serde(default, deserialize_with = "deserialize_null_default")]
+    pub contexts: Vec<InteractionContextType>,
+    /// Whether the command is age-restricted.
+    ///
+    /// **Note**: Discord only allows age-restricted commands to be used in
+    /// NSFW channels, so interactions for them are never sent from other
+    /// channels.
+    #[serde(default)]
+    pub nsfw: bool,
+    /// The number of times the command was installed.
+    ///
+    /// **Note**: Discord only returns it from some endpoints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub install_count: Option<u64>,
+    /// The Id of the SKU required to use the command, if it is a premium
+    /// feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sku_id: Option<SkuId>,
+}
+
+impl ApplicationCommand {
+    /// Whether the command is specific to a guild.
+    #[inline]
+    pub fn is_guild_command(&self) -> bool {
+        self.guild_id.is_some()
+    }
+
+    /// Whether the command is available in all guilds.
+    #[inline]
+    pub fn is_global_command(&self) -> bool {
+        self.guild_id.is_none()
+    }
+
+    /// Computes a hash of the command's definition.
+    ///
+    /// The hash covers every field that is sent to Discord when registering
+    /// the command: the kind, name, description and their localizations, the
+    /// permissions, where the command can be used, and all options, including
+    /// nested ones and their choices. Ids, and fields only sent by Discord such
+    /// as [`name_localized`], are ignored, so a registered command and the one
+    /// currently defined by the bot hash to the same value if they would behave
+    /// the same, which allows skipping API calls at startup when nothing
+    /// changed.
+    ///
+    /// The hash is stable: the same definition always produces the same value,
+    /// across restarts and platforms.
+    ///
+    /// [`name_localized`]: Self::name_localized
+    pub fn definition_hash(&self) -> u64 {
+        // Every field sent to Discord must be written here, and covered by
+        // `test_definition_hash_fields`. Optional fields are only written
+        // when set, each behind its own tag, so that the hashes of
+        // definitions without them stay the same.
+        let mut hasher = DefinitionHasher::new();
+
+        hasher.write_str(&self.name);
+        hasher.write_str(&self.description);
+        #[allow(deprecated)]
+        hasher.write_u8(self.default_permission as u8);
+        hasher.write_options(&self.options);
+
+        if let Some(localizations) = &self.name_localizations {
+            hasher.write_u8(1);
+            hasher.write_localizations(localizations);
+        }
+
+        if let Some(localizations) = &self.description_localizations {
+            hasher.write_u8(2);
+            hasher.write_localizations(localizations);
+        }
+
+        if let Some(permissions) = self.default_member_permissions {
+            hasher.write_u8(3);
+            hasher.write(&permissions.bits().to_le_bytes());
+        }
+
+        if self.kind != ApplicationCommandType::ChatInput {
+            hasher.write_u8(4);
+            hasher.write_u8(self.kind as u8);
+        }
+
+        if let Some(dm_permission) = self.dm_permission {
+            hasher.write_u8(5);
+            hasher.write_u8(dm_permission as u8);
+        }
+
+        if !self.integration_types.is_empty() {
+            hasher.write_u8(6);
+            hasher.write_len(self.integration_types.len());
+            for kind in &self.integration_types {
+                hasher.write_u8(*kind as u8);
+            }
+        }
+
+        if !self.contexts.is_empty() {
+            hasher.write_u8(7);
+            hasher.write_len(self.contexts.len());
+            for context in &self.contexts {
+                hasher.write_u8(*context as u8);
+            }
+        }
+
+        if self.nsfw {
+            hasher.write_u8(8);
+        }
+
+        hasher.finish()
+    }
+
+    /// Creates a global [`ApplicationCommand`],
+    /// overriding an existing one with the same name if it exists.
+    ///
+    /// When a created [`ApplicationCommand`] is used, the [`InteractionCreate`] event will be emitted.
+    ///
+    /// **Note**: Global commands may take up to an hour to become available.
+    ///
+    /// As such, it is recommended that guild application commands be used for testing purposes.
+    ///
+    /// # Examples
+    ///
+    /// Create a simple ping command
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn run() {
+    /// # let http = Arc::new(Http::default());
+    /// use serenity::model::{interactions::ApplicationCommand, id::ApplicationId};
+    ///
+    /// let _ = ApplicationCommand::create_global_application_command(&http, |a| {
+    ///    a.name("ping")
+    ///     .description("A simple ping command")
+    /// })
+    /// .await;
+    /// # }
+    /// ```
+    ///
+    /// Create a command that echoes what is inserted
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn run() {
+    /// # let http = Arc::new(Http::default());
+    /// use serenity::model::{
+    ///     interactions::{ApplicationCommand, ApplicationCommandOptionType},
+    ///     id::ApplicationId
+    /// };
+    ///
+    /// let _ = ApplicationCommand::create_global_application_command(&http, |a| {
+    ///    a.name("echo")
+    ///     .description("What is said is echoed")
+    ///     .create_option(|o| {
+    ///         o.name("to_say")
+    ///          .description("What will be echoed")
+    ///          .kind(ApplicationCommandOptionType::String)
+    ///          .required(true)
+    ///     })
+    /// })
+    /// .await;
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidApplicationCommand`] if the command
+    /// exceeds a limit checked by [`CreateApplicationCommand::validate`],
+    /// such as if more than 25 [`choices`] are set.
+    ///
+    /// May return an [`Error::Http`] if the [`ApplicationCommand`] is otherwise
+    /// illformed. See the [API Docs] for further details.
+    ///
+    /// Can also return an [`Error::Json`] if there is an error in deserializing
+    /// the response.
+    ///
+    /// [`ApplicationCommand`]: crate::model::interactions::ApplicationCommand
+    /// [`InteractionCreate`]: crate::client::EventHandler::interaction_create
+    /// [API Docs]: https://discord.com/developers/docs/interactions/slash-commands
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`Error::Json`]: crate::error::Error::Json
+    /// [`choices`]: crate::model::interactions::ApplicationCommandOption::choices
+    /// [`ModelError::InvalidApplicationCommand`]: crate::model::ModelError::InvalidApplicationCommand
+    pub async fn create_global_application_command<F>(
+        http: impl AsRef<Http>,
+        f: F,
+    ) -> Result<ApplicationCommand>
+    where
+        F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand,
+    {
+        let map = ApplicationCommand::build_application_command(f, false)?;
+        http.as_ref().create_global_application_command(&Value::Object(map)).await
+    }
+
+    /// Same as [`create_global_application_command`] but allows
+    /// to create more than one global command per call.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same possible errors as [`create_global_application_command`],
+    /// checking every command.
+    ///
+    /// [`create_global_application_command`]: Self::create_global_application_command
+    pub async fn create_global_application_commands<F>(
+        http: impl AsRef<Http>,
+        f: F,
+    ) -> Result<Vec<ApplicationCommand>>
+    where
+        F: FnOnce(&mut CreateApplicationCommands) -> &mut CreateApplicationCommands,
+    {
+        let mut array = CreateApplicationCommands::default();
+
+        f(&mut array);
+
+        let commands = ApplicationCommand::validate_application_commands(array)?;
+        http.as_ref().create_global_application_commands(&commands).await
+    }
+
+    /// Edits a global command by its Id.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same possible errors as [`create_global_application_command`],
+    /// except that the name and description may be left out.
+    ///
+    /// [`create_global_application_command`]: Self::create_global_application_command
+    pub async fn edit_global_application_command<F>(
+        http: impl AsRef<Http>,
+        command_id: CommandId,
+        f: F,
+    ) -> Result<ApplicationCommand>
+    where
+        F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand,
+    {
+        let map = ApplicationCommand::build_application_command(f, true)?;
+        http.as_ref().edit_global_application_command(command_id.into(), &Value::Object(map)).await
+    }
+
+    /// Gets all global commands.
+    pub async fn get_global_application_commands(
+        http: impl AsRef<Http>,
+    ) -> Result<Vec<ApplicationCommand>> {
+        http.as_ref().get_global_application_commands().await
+    }
+
+    /// Gets a global command by its Id.
+    pub async fn get_global_application_command(
+        http: impl AsRef<Http>,
+        command_id: CommandId,
+    ) -> Result<ApplicationCommand> {
+        http.as_ref().get_global_application_command(command_id.into()).await
+    }
+
+    /// Deletes a global command by its Id.
+    pub async fn delete_global_application_command(
+        http: impl AsRef<Http>,
+        command_id: CommandId,
+    ) -> Result<()> {
+        http.as_ref().delete_global_application_command(command_id.into()).await
+    }
+
+    /// Deletes all global commands at once.
+    ///
+    /// This overwrites the global commands with an empty list, which is
+    /// how Discord performs a bulk delete.
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn delete_all_global_application_commands(http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().create_global_application_commands(&Value::Array(Vec::new())).await?;
+
+        Ok(())
+    }
+
+    /// Creates a guild specific [`ApplicationCommand`],
+    /// overriding an existing one with the same name if it exists.
+    ///
+    /// This is a shorthand for [`GuildId::create_application_command`].
+    ///
+    /// **Note**: Unlike global commands, guild commands are available instantly.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same possible errors as [`create_global_application_command`].
+    ///
+    /// [`create_global_application_command`]: Self::create_global_application_command
+    pub async fn create_guild_application_command<F>(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+        f: F,
+    ) -> Result<ApplicationCommand>
+    where
+        F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand,
+    {
+        guild_id.create_application_command(http, f).await
+    }
+
+    /// Same as [`create_guild_application_command`] but allows
+    /// to create more than one guild command per call.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same possible errors as [`create_global_application_command`].
+    ///
+    /// [`create_guild_application_command`]: Self::create_guild_application_command
+    /// [`create_global_application_command`]: Self::create_global_application_command
+    pub async fn create_guild_application_commands<F>(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+        f: F,
+    ) -> Result<Vec<ApplicationCommand>>
+    where
+        F: FnOnce(&mut CreateApplicationCommands) -> &mut CreateApplicationCommands,
+    {
+        guild_id.create_application_commands(http, f).await
+    }
+
+    /// Edits a guild command by its Id.
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn edit_guild_application_command<F>(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+        command_id: CommandId,
+        f: F,
+    ) -> Result<ApplicationCommand>
+    where
+        F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand,
+    {
+        guild_id.edit_application_command(http, command_id, f).await
+    }
+
+    /// Gets all commands of a guild.
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn get_guild_application_commands(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+    ) -> Result<Vec<ApplicationCommand>> {
+        guild_id.get_application_commands(http).await
+    }
+
+    /// Gets a guild command by its Id.
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn get_guild_application_command(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+        command_id: CommandId,
+    ) -> Result<ApplicationCommand> {
+        guild_id.get_application_command(http, command_id).await
+    }
+
+    /// Deletes a guild command by its Id.
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn delete_guild_application_command(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+        command_id: CommandId,
+    ) -> Result<()> {
+        guild_id.delete_application_command(http, command_id).await
+    }
+
+    /// Deletes all commands of a guild at once.
+    ///
+    /// This overwrites the guild's commands with an empty list, which is
+    /// how Discord performs a bulk delete.
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn delete_all_guild_application_commands(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+    ) -> Result<()> {
+        http.as_ref()
+            .create_guild_application_commands(guild_id.0, &Value::Array(Vec::new()))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Builds a command and checks it with [`CreateApplicationCommand::validate`].
+    ///
+    /// If `edit` is set, the name and description may be left out.
+    pub(crate) fn build_application_command<F>(f: F, edit: bool) -> Result<Map<String, Value>>
+    where
+        F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand,
+    {
+        let mut create_application_command = CreateApplicationCommand::default();
+        f(&mut create_application_command);
+
+        let result = if edit {
+            create_application_command.validate_edit()
+        } else {
+            create_application_command.validate()
+        };
+        result.map_err(|errors| Error::Model(ModelError::InvalidApplicationCommand(errors)))?;
+
+        Ok(utils::hashmap_to_json_map(create_application_command.0))
+    }
+
+    /// Checks the commands with [`CreateApplicationCommands::validate`],
+    /// returning them as an array.
+    pub(crate) fn validate_application_commands(
+        commands: CreateApplicationCommands,
+    ) -> Result<Value> {
+        commands
+            .validate()
+            .map_err(|errors| Error::Model(ModelError::InvalidApplicationCommand(errors)))?;
+
+        Ok(Value::Array(commands.0))
+    }
+}
+
+impl Eq for ApplicationCommand {}
+
+impl Hash for ApplicationCommand {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Ord for ApplicationCommand {
+    fn cmp(&self, other: &ApplicationCommand) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl PartialEq for ApplicationCommand {
+    fn eq(&self, other: &ApplicationCommand) -> bool {
+        self.id == other.id
+    }
+}
+
+impl PartialOrd for ApplicationCommand {
+    fn partial_cmp(&self, other: &ApplicationCommand) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A 64-bit FNV-1a hasher, used for [`ApplicationCommand::definition_hash`].
+///
+/// Unlike the hashers provided by the standard library, its output does not
+/// depend on the Rust version, the platform or a random seed.
+struct DefinitionHasher(u64);
+
+impl DefinitionHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_u8(&mut self, value: u8) {
+        self.write(&[value]);
+    }
+
+    fn write_len(&mut self, len: usize) {
+        self.write(&(len as u64).to_le_bytes());
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_len(value.len());
+        self.write(value.as_bytes());
+    }
+
+    /// Writes the localizations sorted by locale, as the iteration order of a
+    /// [`HashMap`] is not stable.
+    fn write_localizations(&mut self, localizations: &HashMap<String, String>) {
+        let mut localizations: Vec<_> = localizations.iter().collect();
+        localizations.sort();
+
+        self.write_len(localizations.len());
+        for (locale, text) in localizations {
+            self.write_str(locale);
+            self.write_str(text);
+        }
+    }
+
+    fn write_options(&mut self, options: &[ApplicationCommandOption]) {
+        self.write_len(options.len());
+
+        for option in options {
+            self.write_u8(option.kind as u8);
+            self.write_str(&option.name);
+            self.write_str(&option.description);
+            self.write_u8(option.required as u8);
+
+            self.write_len(option.choices.len());
+            for choice in &option.choices {
+                self.write_str(&choice.name);
+                self.write_str(&choice.value.to_string());
+
+                // A presence byte rather than a tag, as nothing marks where a
+                // choice's tagged fields would end and the option's begin.
+                if let Some(localizations) = &choice.name_localizations {
+                    self.write_u8(1);
+                    self.write_localizations(localizations);
+                } else {
+                    self.write_u8(0);
+                }
+            }
+
+            // Optional fields, tagged as in `ApplicationCommand::definition_hash`
+            // and ended by a zero byte, which no tag uses, so that they cannot
+            // run into the suboptions.
+            if let Some(localizations) = &option.name_localizations {
+                self.write_u8(1);
+                self.write_localizations(localizations);
+            }
+
+            if let Some(localizations) = &option.description_localizations {
+                self.write_u8(2);
+                self.write_localizations(localizations);
+            }
+
+            if option.autocomplete {
+                self.write_u8(3);
+            }
+
+            if !option.channel_types.is_empty() {
+                self.write_u8(4);
+                self.write_len(option.channel_types.len());
+                for kind in &option.channel_types {
+                    self.write_u8(*kind as u8);
+                }
+            }
+
+            if let Some(value) = &option.min_value {
+                self.write_u8(5);
+                self.write_str(&value.to_string());
+            }
+
+            if let Some(value) = &option.max_value {
+                self.write_u8(6);
+                self.write_str(&value.to_string());
+            }
+
+            self.write_u8(0);
+            self.write_options(&option.options);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The parameters for an [`ApplicationCommand`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandOption {
+    /// The option type.
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandOptionType,
+    /// The option name.
+    pub name: String,
+    /// The localized names of the option, keyed by locale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<HashMap<String, String>>,
+    /// The option name in the locale of the user who requested the command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localized: Option<String>,
+    /// The option description.
+    pub description: String,
+    /// The localized descriptions of the option, keyed by locale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<HashMap<String, String>>,
+    /// The option description in the locale of the user who requested the
+    /// command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localized: Option<String>,
+    /// Whether the parameter is optional or required.
+    #[serde(default)]
+    pub required: bool,
+    /// Choices the user can pick from.
+    ///
+    /// **Note**: Only available for [`String`] and [`Integer`] [`ApplicationCommandOptionType`].
+    ///
+    /// [`String`]: ApplicationCommandOptionType::String
+    /// [`Integer`]: ApplicationCommandOptionType::Integer
+    #[serde(default)]
+    pub choices: Vec<ApplicationCommandOptionChoice>,
+    /// The nested options.
+    ///
+    /// **Note**: Only available for [`SubCommand`] or [`SubCommandGroup`].
+    ///
+    /// [`SubCommand`]: ApplicationCommandOptionType::SubCommand
+    /// [`SubCommandGroup`]: ApplicationCommandOptionType::SubCommandGroup
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+    /// Whether autocompletion is enabled for the option, in which case
+    /// [`InteractionType::Autocomplete`] interactions are sent while the user
+    /// is typing.
+    ///
+    /// **Note**: Only available for [`String`], [`Integer`] and [`Number`]
+    /// [`ApplicationCommandOptionType`], and only if there are no [`choices`].
+    ///
+    /// [`String`]: ApplicationCommandOptionType::String
+    /// [`Integer`]: ApplicationCommandOptionType::Integer
+    /// [`Number`]: ApplicationCommandOptionType::Number
+    /// [`choices`]: Self::choices
+    #[serde(default)]
+    pub autocomplete: bool,
+    /// The kinds of channels the user can pick.
+    ///
+    /// **Note**: Only available for [`Channel`] [`ApplicationCommandOptionType`].
+    /// An empty list means that all kinds of channels can be picked.
+    ///
+    /// [`Channel`]: ApplicationCommandOptionType::Channel
+    #[serde(default)]
+    pub channel_types: Vec<ChannelType>,
+    /// The minimum value the user can enter.
+    ///
+    /// **Note**: Only available for [`Integer`] and [`Number`]
+    /// [`ApplicationCommandOptionType`].
+    ///
+    /// [`Integer`]: ApplicationCommandOptionType::Integer
+    /// [`Number`]: ApplicationCommandOptionType::Number
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<Value>,
+    /// The maximum value the user can enter.
+    ///
+    /// **Note**: Only available for [`Integer`] and [`Number`]
+    /// [`ApplicationCommandOptionType`].
+    ///
+    /// [`Integer`]: ApplicationCommandOptionType::Integer
+    /// [`Number`]: ApplicationCommandOptionType::Number
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<Value>,
+}
+
+/// An [`ApplicationCommand`] permission.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandPermission {
+    /// The id of the command.
+    pub id: CommandId,
+    /// The id of the application the command belongs to.
+    pub application_id: ApplicationId,
+    /// The id of the guild.
+    pub guild_id: GuildId,
+    /// The permissions for the command in the guild.
+    pub permissions: Vec<ApplicationCommandPermissionData>,
+}
+
+impl ApplicationCommandPermission {
+    /// Gets the permissions of a guild command.
+    ///
+    /// This is a shorthand for [`GuildId::get_application_command_permissions`].
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the API returns an error, such as if
+    /// the command does not exist.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn get_guild_application_command_permissions(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+        command_id: CommandId,
+    ) -> Result<ApplicationCommandPermission> {
+        guild_id.get_application_command_permissions(http, command_id).await
+    }
+
+    /// Gets the permissions of every command of the application in a guild.
+    ///
+    /// This is a shorthand for [`GuildId::get_application_commands_permissions`].
+    ///
+    /// **Note**: Commands without permissions set in the guild are omitted.
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn get_all_guild_application_command_permissions(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+    ) -> Result<Vec<ApplicationCommandPermission>> {
+        guild_id.get_application_commands_permissions(http).await
+    }
+
+    /// Overwrites the permissions of a guild command.
+    ///
+    /// This is a shorthand for [`GuildId::create_application_command_permission`].
+    ///
+    /// **Note**: Updates to the permissions are available instantly.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyPermissions`] if more than 10
+    /// permissions are set.
+    ///
+    /// May also return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn edit_guild_application_command_permissions<F>(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+        command_id: CommandId,
+        f: F,
+    ) -> Result<ApplicationCommandPermission>
+    where
+        F: FnOnce(
+            &mut CreateApplicationCommandPermissionsData,
+        ) -> &mut CreateApplicationCommandPermissionsData,
+    {
+        guild_id.create_application_command_permission(http, command_id, f).await
+    }
+
+    /// Overwrites the permissions of all commands of a guild at once.
+    ///
+    /// This is a shorthand for [`GuildId::create_application_commands_permissions`].
+    ///
+    /// **Note**: The batch replaces the permissions of every command in the
+    /// guild, so commands which are not part of it lose their permissions. Use
+    /// [`edit_guild_application_command_permissions`] to only edit the
+    /// permissions of one command.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyPermissions`] if a command has more
+    /// than 10 permissions.
+    ///
+    /// May also return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`edit_guild_application_command_permissions`]: Self::edit_guild_application_command_permissions
+    pub async fn batch_edit_guild_application_command_permissions<F>(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+        f: F,
+    ) -> Result<Vec<ApplicationCommandPermission>>
+    where
+        F: FnOnce(
+            &mut CreateApplicationCommandsPermissions,
+        ) -> &mut CreateApplicationCommandsPermissions,
+    {
+        guild_id.create_application_commands_permissions(http, f).await
+    }
+}
+
+/// The [`ApplicationCommandPermission`] data.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandPermissionData {
+    /// The [`RoleId`] or [`UserId`], depends on `kind` value.
+    ///
+    /// [`RoleId`]: crate::model::id::RoleId
+    /// [`UserId`]: crate::model::id::UserId
+    pub id: CommandPermissionId,
+    /// The type of data this permissions applies to.
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandPermissionType,
+    /// Whether or not the provided data can use the command or not.
+    pub permission: bool,
+}
+
+impl ApplicationCommandPermissionData {
+    /// Whether the permission allows the role or user to use the command.
+    #[inline]
+    pub fn allows(&self) -> bool {
+        self.permission
+    }
+
+    /// Whether the permission denies the role or user the use of the command.
+    #[inline]
+    pub fn denies(&self) -> bool {
+        !self.permission
+    }
+
+    /// Whether the permission is for the user with the given Id.
+    pub fn applies_to_user(&self, user_id: UserId) -> bool {
+        self.kind == ApplicationCommandPermissionType::User && self.id.0 == user_id.0
+    }
+
+    /// Whether the permission is for the role with the given Id.
+    pub fn applies_to_role(&self, role_id: RoleId) -> bool {
+        self.kind == ApplicationCommandPermissionType::Role && self.id.0 == role_id.0
+    }
+}
+
+/// The type of an [`ApplicationCommandOption`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ApplicationCommandOptionType {
+    SubCommand = 1,
+    SubCommandGroup = 2,
+    String = 3,
+    Integer = 4,
+    Boolean = 5,
+    User = 6,
+    Channel = 7,
+    Role = 8,
+    Mentionable = 9,
+    Number = 10,
+    Attachment = 11,
+    Unknown = !0,
+}
+
+enum_number!(ApplicationCommandOptionType {
+    SubCommand,
+    SubCommandGroup,
+    String,
+    Integer,
+    Boolean,
+    User,
+    Channel,
+    Role,
+    Mentionable,
+    Number,
+    Attachment,
+});
+
+impl FromStr for ApplicationCommandOptionType {
+    type Err = CommandOptionTypeParseError;
+
+    /// Parses the name of a variant, ignoring case.
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "subcommand" => Ok(ApplicationCommandOptionType::SubCommand),
+            "subcommandgroup" => Ok(ApplicationCommandOptionType::SubCommandGroup),
+            "string" => Ok(ApplicationCommandOptionType::String),
+            "integer" => Ok(ApplicationCommandOptionType::Integer),
+            "boolean" => Ok(ApplicationCommandOptionType::Boolean),
+            "user" => Ok(ApplicationCommandOptionType::User),
+            "channel" => Ok(ApplicationCommandOptionType::Channel),
+            "role" => Ok(ApplicationCommandOptionType::Role),
+            "mentionable" => Ok(ApplicationCommandOptionType::Mentionable),
+            "number" => Ok(ApplicationCommandOptionType::Number),
+            "attachment" => Ok(ApplicationCommandOptionType::Attachment),
+            "unknown" => Ok(ApplicationCommandOptionType::Unknown),
+            _ => Err(CommandOptionTypeParseError(s.to_string())),
+        }
+    }
+}
+
+/// An error returned when parsing an invalid [`ApplicationCommandOptionType`].
+///
+/// The string that failed to parse is provided.
+#[derive(Clone, Debug)]
+pub struct CommandOptionTypeParseError(pub String);
+
+impl fmt::Display for CommandOptionTypeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown command option type: {}", self.0)
+    }
+}
+
+impl std::error::Error for CommandOptionTypeParseError {}
+
+/// The type of an [`ApplicationCommand`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ApplicationCommandType {
+    /// A slash command, invoked by typing its name in the chat input.
+    ChatInput = 1,
+    /// A context menu command, shown when right clicking a user.
+    User = 2,
+    /// A context menu command, shown when right clicking a message.
+    Message = 3,
+    Unknown = !0,
+}
+
+enum_number!(ApplicationCommandType {
+    ChatInput,
+    User,
+    Message
+});
+
+/// The installation context of an application, used to specify where an
+/// [`ApplicationCommand`] is available.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ApplicationIntegrationType {
+    /// Installed to a guild.
+    GuildInstall = 0,
+    /// Installed to a user.
+    UserInstall = 1,
+    Unknown = !0,
+}
+
+enum_number!(ApplicationIntegrationType {
+    GuildInstall,
+    UserInstall
+});
+
+/// The context an [`ApplicationCommand`] can be used in.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum InteractionContextType {
+    /// Used within guild channels.
+    Guild = 0,
+    /// Used within the DM channel with the bot.
+    BotDm = 1,
+    /// Used within group DMs and DMs other than the one with the bot.
+    PrivateChannel = 2,
+    Unknown = !0,
+}
+
+enum_number!(InteractionContextType {
+    Guild,
+    BotDm,
+    PrivateChannel
+});
+
+/// The type of an [`ApplicationCommandPermissionData`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ApplicationCommandPermissionType {
+    Role = 1,
+    User = 2,
+    Unknown = !0,
+}
+
+enum_number!(ApplicationCommandPermissionType {
+    Role,
+    User
+});
+
+impl fmt::Display for ApplicationCommandPermissionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ApplicationCommandPermissionType::Role => "role",
+            ApplicationCommandPermissionType::User => "user",
+            ApplicationCommandPermissionType::Unknown => "unknown",
+        })
+    }
+}
+
+impl FromStr for ApplicationCommandPermissionType {
+    type Err = PermissionTypeParseError;
+
+    /// Parses the output of the [`Display`] implementation, ignoring case.
+    ///
+    /// [`Display`]: fmt::Display
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "role" => Ok(ApplicationCommandPermissionType::Role),
+            "user" => Ok(ApplicationCommandPermissionType::User),
+            "unknown" => Ok(ApplicationCommandPermissionType::Unknown),
+            _ => Err(PermissionTypeParseError(s.to_string())),
+        }
+    }
+}
+
+/// An error returned when parsing an invalid [`ApplicationCommandPermissionType`].
+///
+/// The string that failed to parse is provided.
+#[derive(Clone, Debug)]
+pub struct PermissionTypeParseError(pub String);
+
+impl fmt::Display for PermissionTypeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown permission type: {}", self.0)
+    }
+}
+
+impl std::error::Error for PermissionTypeParseError {}
+
+/// The only valid values a user can pick in an [`ApplicationCommandOption`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandOptionChoice {
+    /// The choice name.
+    pub name: String,
+    /// The localized names of the choice, keyed by locale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<HashMap<String, String>>,
+    /// The choice name in the locale of the user who requested the command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localized: Option<String>,
+    /// The choice value.
+    pub value: Value,
+}
+
+impl ApplicationCommandOptionChoice {
+    /// Creates a choice with the given name and value.
+    ///
+    /// The value should be a string or an integer, matching the type of the
+    /// option.
+    pub fn new(name: impl ToString, value: impl Into<Value>) -> Self {
+        Self {
+            name: name.to_string(),
+            name_localizations: None,
+            name_localized: None,
+            value: value.into(),
+        }
+    }
+}
+
+/// The available responses types for an interaction response.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum InteractionResponseType {
+    Pong = 1,
+    ChannelMessageWithSource = 4,
+    DeferredChannelMessageWithSource = 5,
+    DeferredUpdateMessage = 6,
+    UpdateMessage = 7,
+    Autocomplete = 8,
+    Modal = 9,
+}
+
+bitflags! {
+    /// The flags for an interaction response.
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct InteractionApplicationCommandCallbackDataFlags: u64 {
+        /// Interaction message will not include any embeds for the links in
+        /// its content.
+        const SUPPRESS_EMBEDS = 0b0000_0000_0000_0000_0000_0000_0000_0100;
+        /// Interaction message will only be visible to sender and will
+        /// be quickly deleted.
+        const EPHEMERAL = 0b0000_0000_0000_0000_0000_0000_0100_0000;
+        /// Interaction message is a loading state, such as the "thinking"
+        /// message shown while a response is deferred.
+        const LOADING = 0b0000_0000_0000_0000_0000_0000_1000_0000;
+    }
+}
+
+/// Sent when a [`Message`] is a response to an [`Interaction`].
+///
+/// [`Message`]: crate::model::channel::Message
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MessageInteraction {
+    /// The id of the interaction.
+    pub id: InteractionId,
+    /// The type of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The name of the [`ApplicationCommand`].
+    pub name: String,
+    /// The user who invoked the interaction.
+    pub user: User,
+}
+
+impl MessageInteraction {
+    /// Returns the avatar URL of the user who invoked the interaction, if they
+    /// have one.
+    ///
+    /// This is a shorthand for [`User::avatar_url`].
+    #[inline]
+    pub fn user_avatar_url(&self) -> Option<String> {
+        self.user.avatar_url()
+    }
+}
+
+impl Interaction {
+    /// Gets the name of the action that triggered the interaction.
+    ///
+    /// This is the command name for application command and autocomplete
+    /// interactions, and the custom Id of the component or modal for message
+    /// component and modal submit interactions. Returns `None` for pings.
+    pub fn command_name(&self) -> Option<&str> {
+        if let Some(data) = &self.data {
+            return Some(&data.name);
+        }
+
+        if let Some(data) = &self.component_data {
+            return Some(&data.custom_id);
+        }
+
+        self.modal_data.as_ref().map(|data| data.custom_id.as_str())
+    }
+
+    /// Gets the Id of the channel the interaction was sent from.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelInteraction`] if the interaction
+    /// was not sent from a channel, which is only the case for
+    /// [`InteractionType::Ping`]s.
+    pub fn channel_id_or_err(&self) -> Result<ChannelId> {
+        self.channel_id.ok_or(Error::Model(ModelError::InvalidChannelInteraction))
+    }
+
+    /// Whether the bot has the given permissions in the channel the
+    /// interaction was sent from.
+    ///
+    /// **Note**: Returns `false` if Discord did not send the bot's
+    /// permissions.
+    pub fn bot_can(&self, permissions: Permissions) -> bool {
+        matches!(self.app_permissions, Some(p) if p.contains(permissions))
+    }
+
+    /// Whether the application was installed to the invoking user, rather
+    /// than to the guild, to authorize the interaction.
+    pub fn installed_by_user(&self) -> bool {
+        let owner =
+            self.authorizing_integration_owners.get(&ApplicationIntegrationType::UserInstall);
+
+        match (owner, self.invoke_user_id()) {
+            (Some(owner), Some(user_id)) => *owner == user_id.0,
+            _ => false,
+        }
+    }
+
+    /// Gets the user who invoked the interaction.
+    ///
+    /// **Note**: When the interaction is sent from a guild, the user is taken
+    /// from [`Self::member`], otherwise from [`Self::user`]. It is only `None`
+    /// for [`InteractionType::Ping`] interactions, which have neither.
+    pub fn invoke_user(&self) -> Option<&User> {
+        self.member.as_ref().map(|m| &m.user).or(self.user.as_ref())
+    }
+
+    /// Gets the Id of the user who invoked the interaction.
+    ///
+    /// This is the same as [`Self::invoke_user`], but only returns the Id.
+    pub fn invoke_user_id(&self) -> Option<UserId> {
+        self.invoke_user().map(|user| user.id)
+    }
+
+    /// Whether the invoking user or guild has an active entitlement to the
+    /// given SKU.
+    pub fn has_entitlement(&self, sku_id: impl Into<SkuId>) -> bool {
+        let sku_id = sku_id.into();
+
+        self.entitlements.iter().any(|e| e.sku_id == sku_id && e.is_active())
+    }
+
+    /// Returns the guild the interaction was sent from, if it is in the cache.
+    ///
+    /// Returns [`None`] if the interaction was not sent from a guild or if
+    /// the guild is not cached.
+    ///
+    /// Requires the `cache` feature be enabled.
+    #[cfg(feature = "cache")]
+    pub async fn guild(&self, cache: impl AsRef<Cache>) -> Option<Guild> {
+        cache.as_ref().guild(self.guild_id?).await
+    }
+
+    /// Returns the channel the interaction was sent from, if it is in the
+    /// cache.
+    ///
+    /// Returns [`None`] if the interaction was not sent from a channel, as is
+    /// the case for pings, or if the channel is not cached.
+    ///
+    /// Requires the `cache` feature be enabled.
+    #[cfg(feature = "cache")]
+    pub async fn channel(&self, cache: impl AsRef<Cache>) -> Option<Channel> {
+        cache.as_ref().channel(self.channel_id?).await
+    }
+
+    /// Gets a handle to the interaction token, which is redacted when
+    /// formatted.
+    #[inline]
+    pub fn safe_token(&self) -> SafeToken {
+        self.token.clone()
+    }
+
+    /// Gets the interaction as an [`AutocompleteInteraction`], or `None` if it
+    /// is not of the [`InteractionType::Autocomplete`] kind.
+    pub fn as_autocomplete(&self) -> Option<AutocompleteInteraction<'_>> {
+        match self.kind {
+            InteractionType::Autocomplete => Some(AutocompleteInteraction(self)),
+            _ => None,
+        }
+    }
+
+    /// Gets the interaction as a [`ComponentInteraction`], or `None` if it is
+    /// not of the [`InteractionType::MessageComponent`] kind.
+    pub fn as_component(&self) -> Option<ComponentInteraction<'_>> {
+        match (self.kind, &self.component_data) {
+            (InteractionType::MessageComponent, Some(data)) => {
+                Some(ComponentInteraction(self, data))
+            },
+            _ => None,
+        }
+    }
+
+    /// Gets the selected language of the invoking user.
+    #[inline]
+    pub fn locale_as_str(&self) -> &str {
+        &self.locale
+    }
+
+    /// Gets the preferred locale of the guild the interaction was triggered
+    /// in, if there is one.
+    #[inline]
+    pub fn guild_locale(&self) -> Option<&str> {
+        self.guild_locale.as_deref()
+    }
+
+    /// Gets a [`TokenDeadlineWatcher`] for the time left to send the initial
+    /// response.
+    pub fn deadline_watcher(&self) -> TokenDeadlineWatcher {
+        TokenDeadlineWatcher::new(self.id)
+    }
+
+    /// Creates a response to the interaction received.
+    ///
+    /// A warning is logged if the response is sent more than 2 seconds after
+    /// the interaction was created, as Discord rejects responses sent after 3
+    /// seconds. See [`TokenDeadlineWatcher`].
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Model`] if the message content is too long,
+    /// or if a select menu has more options selected by default than its
+    /// maximum number of values.
+    /// May also return an [`Error::Http`] if the API returns an error,
+    /// or an [`Error::Json`] if there is an error in deserializing the
+    /// API response.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Model`]: crate::error::Error::Model
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`Error::Json`]: crate::error::Error::Json
+    pub async fn create_interaction_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut CreateInteractionResponse) -> &mut CreateInteractionResponse,
+    {
+        let mut interaction_response = CreateInteractionResponse::default();
+        f(&mut interaction_response);
+
+        let map = utils::hashmap_to_json_map(interaction_response.0);
+
+        Message::check_content_length(&map)?;
+        Message::check_embed_length(&map)?;
+        Message::check_components(&map)?;
+
+        self.deadline_watcher().warn_if_late();
+
+        http.as_ref()
+            .create_interaction_response(self.id.0, self.token.expose(), &Value::Object(map))
+            .await
+    }
+
+    /// Edits the initial interaction response.
+    ///
+    /// Refer to Discord's docs for Edit Webhook Message for field information.
+    ///
+    /// **Note**:   Message contents must be under 2000 unicode code points, does not work on ephemeral messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Model`] if the edited content is too long,
+    /// or if a select menu has more options selected by default than its
+    /// maximum number of values.
+    /// May also return [`Error::Http`] if the API returns an error,
+    /// or an [`Error::Json`] if there is an error deserializing the response.
+    ///
+    /// [`Error::Model`]: crate::error::Error::Model
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`Error::Json`]: crate::error::Error::Json
+    pub async fn edit_interaction_response<F>(
+        &self,
+        http: impl AsRef<Http>,
+        f: F,
+    ) -> Result<Message>
+    where
+        F: FnOnce(&mut EditInteractionResponse) -> &mut EditInteractionResponse,
+    {
+        self._edit_interaction_response(http, self.application_id.0, f).await
+    }
+
+    /// Edits the initial interaction response.
+    ///
+    /// `application_id` will usually be the bot's [`UserId`], except in cases
+    /// of bots being very old.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::edit_interaction_response`].
+    ///
+    /// [`UserId`]: crate::model::id::UserId
+    #[deprecated(note = "use `edit_interaction_response`, which uses the interaction's own \
+                         `application_id`")]
+    pub async fn edit_original_interaction_response<F>(
+        &self,
+        http: impl AsRef<Http>,
+        application_id: u64,
+        f: F,
+    ) -> Result<Message>
+    where
+        F: FnOnce(&mut EditInteractionResponse) -> &mut EditInteractionResponse,
+    {
+        self._edit_interaction_response(http, application_id, f).await
+    }
+
+    async fn _edit_interaction_response<F>(
+        &self,
+        http: impl AsRef<Http>,
+        application_id: u64,
+        f: F,
+    ) -> Result<Message>
+    where
+        F: FnOnce(&mut EditInteractionResponse) -> &mut EditInteractionResponse,
+    {
+        let mut interaction_response = EditInteractionResponse::default();
+        f(&mut interaction_response);
+
+        let map = utils::hashmap_to_json_map(interaction_response.0);
+
+        Message::check_content_length(&map)?;
+        Message::check_embed_length(&map)?;
+        Message::check_components(&map)?;
+
+        http.as_ref()
+            .edit_original_interaction_response(
+                application_id,
+                self.token.expose(),
+                &Value::Object(map),
+            )
+            .await
+    }
+
+    /// Defers the response to the interaction, runs the given `work`, then
+    /// edits the deferred response with the [`CreateResponse`] it returns.
+    ///
+    /// This is useful when the work takes longer than the 3 seconds Discord
+    /// allows before a response must be sent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::interactions::Interaction;
+    /// use serenity::builder::CreateResponse;
+    ///
+    /// # async fn run(http: &Http, interaction: Interaction) -> serenity::Result<()> {
+    /// interaction
+    ///     .respond_deferred_then(http, || async {
+    ///         let mut response = CreateResponse::default();
+    ///         response.content("Done!");
+    ///
+    ///         Ok(response)
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by `work`, in which case the deferred
+    /// response is left as is.
+    /// Returns [`Error::Model`] if the content returned by `work` is too long.
+    /// May also return [`Error::Http`] if the API returns an error,
+    /// or an [`Error::Json`] if there is an error deserializing the response.
+    ///
+    /// [`Error::Model`]: crate::error::Error::Model
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`Error::Json`]: crate::error::Error::Json
+    pub async fn respond_deferred_then<F, Fut>(
+        &self,
+        http: impl AsRef<Http>,
+        work: F,
+    ) -> Result<Message>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<CreateResponse>>,
+    {
+        let http = http.as_ref();
+
+        self.create_interaction_response(http, |r| {
+            r.kind(InteractionResponseType::DeferredChannelMessageWithSource)
+        })
+        .await?;
+
+        let response = work().await?;
+
+        self.edit_interaction_response(http, |r| response.apply(r)).await
+    }
+
+    /// Responds to the interaction with a message holding the given content
+    /// and action rows of components.
+    ///
+    /// This is a shorthand for [`Self::create_interaction_response`] with a
+    /// [`ChannelMessageWithSource`] response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Model`] if the content is too long.
+    /// May also return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`ChannelMessageWithSource`]: InteractionResponseType::ChannelMessageWithSource
+    /// [`Error::Model`]: crate::error::Error::Model
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn respond_with_components(
+        &self,
+        http: impl AsRef<Http>,
+        content: impl Into<String>,
+        components: Vec<CreateActionRow>,
+    ) -> Result<()> {
+        self._respond_with_components(http, content.into(), components, false).await
+    }
+
+    /// Responds to the interaction with an ephemeral message, only visible to
+    /// the invoking user, holding the given content and action rows of
+    /// components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Model`] if the content is too long.
+    /// May also return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Model`]: crate::error::Error::Model
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn respond_ephemeral_with_components(
+        &self,
+        http: impl AsRef<Http>,
+        content: impl Into<String>,
+        components: Vec<CreateActionRow>,
+    ) -> Result<()> {
+        self._respond_with_components(http, content.into(), components, true).await
+    }
+
+    /// Acknowledges a component interaction without changing the message the
+    /// component is attached to, so that it can be edited later with
+    /// [`Self::edit_interaction_response`].
+    ///
+    /// This is a shorthand for [`Self::create_interaction_response`] with a
+    /// [`DeferredUpdateMessage`] response.
+    ///
+    /// **Note**: This is only valid for [`MessageComponent`] interactions.
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`DeferredUpdateMessage`]: InteractionResponseType::DeferredUpdateMessage
+    /// [`MessageComponent`]: InteractionType::MessageComponent
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn defer_component_update(&self, http: impl AsRef<Http>) -> Result<()> {
+        self.create_interaction_response(http, |r| {
+            r.kind(InteractionResponseType::DeferredUpdateMessage)
+        })
+        .await
+    }
+
+    /// Responds to an [`Autocomplete`] interaction with the choices to
+    /// suggest to the user.
+    ///
+    /// This is a shorthand for [`AutocompleteInteraction::respond_with_choices`].
+    ///
+    /// **Note**: Discord allows at most 25 choices.
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the API returns an error, such as if
+    /// the interaction is not of the [`Autocomplete`] kind.
+    ///
+    /// [`Autocomplete`]: InteractionType::Autocomplete
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn create_autocomplete_response(
+        &self,
+        http: impl AsRef<Http>,
+        choices: Vec<ApplicationCommandOptionChoice>,
+    ) -> Result<()> {
+        AutocompleteInteraction(self).respond_with_choices(http, choices).await
+    }
+
+    async fn _respond_with_components(
+        &self,
+        http: impl AsRef<Http>,
+        content: String,
+        components: Vec<CreateActionRow>,
+        ephemeral: bool,
+    ) -> Result<()> {
+        self.create_interaction_response(http, |r| {
+            r.kind(InteractionResponseType::ChannelMessageWithSource).interaction_response_data(
+                |d| {
+                    if ephemeral {
+                        d.flags(InteractionApplicationCommandCallbackDataFlags::EPHEMERAL);
+                    }
+
+                    d.content(content).components(|c| c.set_action_rows(components))
+                },
+            )
+        })
+        .await
+    }
+
+    /// Gets the initial interaction response, such as to get the Id of the
+    /// message after deferring the response.
+    ///
+    /// # Errors
+    ///
+    /// May return [`Error::Http`] if the API returns an error,
+    /// such as if there is no response yet,
+    /// or an [`Error::Json`] if there is an error deserializing the response.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`Error::Json`]: crate::error::Error::Json
+    pub async fn get_interaction_response(
+        &self,
+        http: impl AsRef<Http>,
+    ) -> Result<Message> {
+        http.as_ref()
+            .get_original_interaction_response(self.application_id.0, self.token.expose())
+            .await
+    }
+
+    /// Deletes the initial interaction response.
+    ///
+    /// # Errors
+    ///
+    /// May return [`Error::Http`] if the API returns an error.
+    /// Such as if the response was already deleted.
+    pub async fn delete_interaction_response(&self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref()
+            .delete_original_interaction_response(self.application_id.0, self.token.expose())
+            .await
+    }
+
+    /// Deletes the initial interaction response.
+    ///
+    /// `application_id` will usually be the bot's [`UserId`], except in cases
+    /// of bots being very old.
+    ///
+    /// # Errors
+    ///
+    /// May return [`Error::Http`] if the API returns an error.
+    /// Such as if the response was already deleted.
+    ///
+    /// [`UserId`]: crate::model::id::UserId
+    #[deprecated(note = "use `delete_interaction_response`, which uses the interaction's own \
+                         `application_id`")]
+    pub async fn delete_original_interaction_response(
+        &self,
+        http: impl AsRef<Http>,
+        application_id: u64,
+    ) -> Result<()> {
+        http.as_ref()
+            .delete_original_interaction_response(application_id, self.token.expose())
+            .await
+    }
+
+    /// Edits a followup message created with [`Self::create_followup`]
+    /// or [`Self::create_followup_message_and_wait`].
+    ///
+    /// Refer to Discord's docs for Edit Webhook Message for field information.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Model`] if the edited content is too long,
+    /// or if a select menu has more options selected by default than its
+    /// maximum number of values.
+    /// May also return [`Error::Http`] if the API returns an error, such as if
+    /// there is no followup message with the given Id,
+    /// or an [`Error::Json`] if there is an error deserializing the response.
+    ///
+    /// [`Error::Model`]: crate::error::Error::Model
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`Error::Json`]: crate::error::Error::Json
+    pub async fn edit_followup_message<F>(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        f: F,
+    ) -> Result<Message>
+    where
+        F: FnOnce(&mut EditInteractionResponse) -> &mut EditInteractionResponse,
+    {
+        let mut interaction_response = EditInteractionResponse::default();
+        f(&mut interaction_response);
+
+        let map = utils::hashmap_to_json_map(interaction_response.0);
+
+        Message::check_content_length(&map)?;
+        Message::check_embed_length(&map)?;
+        Message::check_components(&map)?;
+
+        http.as_ref()
+            .edit_followup_message(
+                self.application_id.0,
+                self.token.expose(),
+                message_id.into().0,
+                &Value::Object(map),
+            )
+            .await
+    }
+
+    /// Gets a followup message by its Id.
+    ///
+    /// # Errors
+    ///
+    /// May return [`Error::Http`] if the API returns an error, such as if
+    /// there is no followup message with the given Id,
+    /// or an [`Error::Json`] if there is an error deserializing the response.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`Error::Json`]: crate::error::Error::Json
+    pub async fn get_followup_message(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+    ) -> Result<Message> {
+        let message_id = message_id.into().0;
+
+        http.as_ref()
+            .get_followup_message(self.application_id.0, self.token.expose(), message_id)
+            .await
+    }
+
+    /// Deletes a followup message.
+    ///
+    /// # Errors
+    ///
+    /// May return [`Error::Http`] if the API returns an error.
+    /// Such as if the message was already deleted.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn delete_followup_message(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+    ) -> Result<()> {
+        let message_id = message_id.into().0;
+
+        http.as_ref()
+            .delete_followup_message(self.application_id.0, self.token.expose(), message_id)
+            .await
+    }
+
+    /// Creates a followup response to the response sent.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// **Note**: If `wait` is `false`, the created message is not returned,
+    /// so it cannot be edited or deleted later. Use
+    /// [`Self::create_followup_message_and_wait`] to always get the message.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::Model`] if the content is too long,
+    /// or if a select menu has more options selected by default than its
+    /// maximum number of values.
+    /// May also return [`Error::Http`] if the API returns an error,
+    /// or a [`Error::Json`] if there is an error in deserializing the response.
+    ///
+    /// [`Error::Model`]: crate::error::Error::Model
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`Error::Json`]: crate::error::Error::Json
+    pub async fn create_followup<'a, F>(
+        &self,
+        http: impl AsRef<Http>,
+        wait: bool,
+        f: F,
+    ) -> Result<Option<Message>>
+    where
+        for<'b> F: FnOnce(
+            &'b mut CreateInteractionResponseFollowup<'a>,
+        ) -> &'b mut CreateInteractionResponseFollowup<'a>,
+    {
+        self._create_followup(http, self.application_id.0, wait, f).await
+    }
+
+    /// Creates a followup response to the response sent.
+    ///
+    /// `application_id` will usually be the bot's [`UserId`], except in cases
+    /// of bots being very old.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::create_followup`].
+    ///
+    /// [`UserId`]: crate::model::id::UserId
+    #[deprecated(note = "use `create_followup`, which uses the interaction's own `application_id`")]
+    pub async fn create_followup_message<'a, F>(
+        &self,
+        http: impl AsRef<Http>,
+        application_id: u64,
+        wait: bool,
+        f: F,
+    ) -> Result<Option<Message>>
+    where
+        for<'b> F: FnOnce(
+            &'b mut CreateInteractionResponseFollowup<'a>,
+        ) -> &'b mut CreateInteractionResponseFollowup<'a>,
+    {
+        self._create_followup(http, application_id, wait, f).await
+    }
+
+    async fn _create_followup<'a, F>(
+        &self,
+        http: impl AsRef<Http>,
+        application_id: u64,
+        wait: bool,
+        f: F,
+    ) -> Result<Option<Message>>
+    where
+        for<'b> F: FnOnce(
+            &'b mut CreateInteractionResponseFollowup<'a>,
+        ) -> &'b mut CreateInteractionResponseFollowup<'a>,
+    {
+        let mut interaction_response = CreateInteractionResponseFollowup::default();
+        f(&mut interaction_response);
+
+        let map = utils::hashmap_to_json_map(interaction_response.0);
+
+        Message::check_content_length(&map)?;
+        Message::check_embed_length(&map)?;
+        Message::check_components(&map)?;
+
+        http.as_ref()
+            .create_followup_message(application_id, self.token.expose(), wait, &map)
+            .await
+    }
+
+    /// Creates a followup response to the response sent, returning the
+    /// created message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::Model`] if the content is too long,
+    /// or if a select menu has more options selected by default than its
+    /// maximum number of values.
+    /// May also return [`Error::Http`] if the API returns an error,
+    /// or a [`Error::Json`] if there is an error in deserializing the response.
+    ///
+    /// [`Error::Model`]: crate::error::Error::Model
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`Error::Json`]: crate::error::Error::Json
+    pub async fn create_followup_message_and_wait<'a, F>(
+        &self,
+        http: impl AsRef<Http>,
+        f: F,
+    ) -> Result<Message>
+    where
+        for<'b> F: FnOnce(
+            &'b mut CreateInteractionResponseFollowup<'a>,
+        ) -> &'b mut CreateInteractionResponseFollowup<'a>,
+    {
+        let mut interaction_response = CreateInteractionResponseFollowup::default();
+        f(&mut interaction_response);
+
+        let map = utils::hashmap_to_json_map(interaction_response.0);
+
+        Message::check_content_length(&map)?;
+        Message::check_embed_length(&map)?;
+        Message::check_components(&map)?;
+
+        http.as_ref()
+            .create_followup_message_and_wait(self.application_id.0, self.token.expose(), &map)
+            .await
+    }
+}
+
+/// Tracks the time left to send the initial response to an [`Interaction`].
+///
+/// Discord only accepts the initial response within 3 seconds of the
+/// interaction being created, after which the user is shown that the
+/// interaction failed. The creation time is taken from the [`InteractionId`].
+///
+/// A response that takes longer to prepare should be deferred with
+/// [`InteractionResponseType::DeferredChannelMessageWithSource`] first.
+#[derive(Clone, Copy, Debug)]
+pub struct TokenDeadlineWatcher {
+    id: InteractionId,
+    created_at: DateTime<Utc>,
+}
+
+impl TokenDeadlineWatcher {
+    /// The time Discord allows for sending the initial response.
+    pub const DEADLINE: StdDuration = StdDuration::from_secs(3);
+
+    /// The time after which sending the initial response is considered late.
+    pub const WARN_AFTER: StdDuration = StdDuration::from_secs(2);
+
+    /// Creates a watcher for the interaction with the given Id.
+    pub fn new(id: InteractionId) -> Self {
+        Self {
+            id,
+            created_at: id.created_at(),
+        }
+    }
+
+    /// Gets the time elapsed since the interaction was created.
+    ///
+    /// Returns a zero duration if the local clock is behind Discord's.
+    pub fn elapsed(&self) -> StdDuration {
+        (Utc::now() - self.created_at).to_std().unwrap_or_default()
+    }
+
+    /// Gets the time left to send the initial response, which is zero once the
+    /// deadline has passed.
+    pub fn remaining(&self) -> StdDuration {
+        Self::DEADLINE.checked_sub(self.elapsed()).unwrap_or_default()
+    }
+
+    /// Whether more than [`Self::WARN_AFTER`] has elapsed.
+    pub fn is_late(&self) -> bool {
+        self.elapsed() > Self::WARN_AFTER
+    }
+
+    pub(crate) fn warn_if_late(&self) {
+        let elapsed = self.elapsed();
+
+        if elapsed > Self::WARN_AFTER {
+            warn!(
+                "Responding to interaction {} {:?} after it was created, close to the {:?} deadline",
+                self.id,
+                elapsed,
+                Self::DEADLINE
+            );
+        }
+    }
+}
+
+/// An [`Interaction`] sent while a user is typing in an option with
+/// autocompletion enabled.
+///
+/// Created with [`Interaction::as_autocomplete`]. It dereferences to the
+/// underlying [`Interaction`].
+#[derive(Clone, Copy, Debug)]
+pub struct AutocompleteInteraction<'a>(&'a Interaction);
+
+impl AutocompleteInteraction<'_> {
+    /// Gets the option the user is currently typing in.
+    pub fn focused_option(&self) -> Option<&ApplicationCommandInteractionDataOption> {
+        fn find(
+            options: &[ApplicationCommandInteractionDataOption],
+        ) -> Option<&ApplicationCommandInteractionDataOption> {
+            options.iter().find_map(|option| {
+                if option.focused {
+                    Some(option)
+                } else {
+                    find(&option.options)
+                }
+            })
+        }
+
+        self.0.data.as_ref().and_then(|data| find(&data.options))
+    }
+
+    /// Responds with the choices to suggest to the user.
+    ///
+    /// **Note**: Discord allows at most 25 choices.
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the API returns an error, such as if
+    /// more than 25 choices are given.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn respond_with_choices(
+        &self,
+        http: impl AsRef<Http>,
+        choices: Vec<ApplicationCommandOptionChoice>,
+    ) -> Result<()> {
+        let map = json!({
+            "type": InteractionResponseType::Autocomplete as u8,
+            "data": {
+                "choices": choices,
+            },
+        });
+
+        http.as_ref().create_interaction_response(self.0.id.0, self.0.token.expose(), &map).await
+    }
+}
+
+impl Deref for AutocompleteInteraction<'_> {
+    type Target = Interaction;
+
+    fn deref(&self) -> &Interaction {
+        self.0
+    }
+}
+
+/// An [`Interaction`] sent when a user uses a message component, such as
+/// clicking a button.
+///
+/// Created with [`Interaction::as_component`]. It dereferences to the
+/// underlying [`Interaction`].
+///
+/// The `e15_message_components` example shows the whole flow, from sending a
+/// message with buttons to updating it when a button is clicked.
+#[derive(Clone, Copy, Debug)]
+pub struct ComponentInteraction<'a>(&'a Interaction, &'a MessageComponentInteractionData);
+
+impl ComponentInteraction<'_> {
+    /// Gets the custom Id of the component that was used.
+    pub fn custom_id(&self) -> &str {
+        &self.1.custom_id
+    }
+
+    /// Gets the type of the component that was used.
+    pub fn component_type(&self) -> ComponentType {
+        self.1.component_type
+    }
+
+    /// Gets the values chosen by the user, which are only present for select
+    /// menus.
+    pub fn values(&self) -> &[String] {
+        &self.1.values
+    }
+
+    /// Responds to the interaction by editing the message the component is
+    /// attached to.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Model`] if the message content is too long.
+    /// May also return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Model`]: crate::error::Error::Model
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn update_message<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut CreateInteractionResponseData) -> &mut CreateInteractionResponseData,
+    {
+        self.0
+            .create_interaction_response(http, |r| {
+                r.kind(InteractionResponseType::UpdateMessage).interaction_response_data(f)
+            })
+            .await
+    }
+}
+
+impl Deref for ComponentInteraction<'_> {
+    type Target = Interaction;
+
+    fn deref(&self) -> &Interaction {
+        self.0
+    }
+}
+
+/// Convenience methods for extracting typed option values from an
+/// [`ApplicationCommandInteractionData`].
+///
+/// Options are looked up by name, including the options nested inside of
+/// sub-commands and sub-command groups.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::model::interactions::prelude::*;
+/// use serenity::model::interactions::ApplicationCommandInteractionData;
+///
+/// fn greet(data: &ApplicationCommandInteractionData) -> serenity::Result<String> {
+///     let name = data.string("name")?;
+///     let times = data.integer("times")?;
+///
+///     Ok(format!("Hello {}!", name).repeat(times as usize))
+/// }
+/// ```
+pub trait OptionExtract {
+    /// Returns the value of the string option with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::OptionNotFound`] if there is no option with the
+    /// given name, or [`ModelError::InvalidOptionType`] if it is not a string.
+    fn string(&self, name: &str) -> Result<String>;
+
+    /// Returns the value of the integer option with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::OptionNotFound`] if there is no option with the
+    /// given name, or [`ModelError::InvalidOptionType`] if it is not an integer.
+    fn integer(&self, name: &str) -> Result<i64>;
+
+    /// Returns the value of the boolean option with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::OptionNotFound`] if there is no option with the
+    /// given name, or [`ModelError::InvalidOptionType`] if it is not a boolean.
+    fn boolean(&self, name: &str) -> Result<bool>;
+
+    /// Returns the user given to the user option with the given name, along
+    /// with their member data if the command was used in a guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::OptionNotFound`] if there is no option with the
+    /// given name, or [`ModelError::InvalidOptionType`] if it is not a user.
+    fn user(&self, name: &str) -> Result<(&User, Option<&PartialMember>)>;
+
+    /// Returns the role given to the role option with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::OptionNotFound`] if there is no option with the
+    /// given name, or [`ModelError::InvalidOptionType`] if it is not a role.
+    fn role(&self, name: &str) -> Result<&Role>;
+
+    /// Returns the channel given to the channel option with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModelError::OptionNotFound`] if there is no option with the
+    /// given name, or [`ModelError::InvalidOptionType`] if it is not a channel.
+    fn channel(&self, name: &str) -> Result<&PartialChannel>;
+}
+
+fn find_option<'a>(
+    options: &'a [ApplicationCommandInteractionDataOption],
+    name: &str,
+) -> Option<&'a ApplicationCommandInteractionDataOption> {
+    options.iter().find_map(|option| {
+        if option.name == name {
+            Some(option)
+        } else {
+            find_option(&option.options, name)
+        }
+    })
+}
+
+impl ApplicationCommandInteractionData {
+    /// Gets a typed view of the option with the given name, or `None` if there
+    /// is no such option.
+    ///
+    /// Like [`OptionExtract`], this looks up the options nested inside of
+    /// sub-commands and sub-command groups as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::interactions::ApplicationCommandInteractionData;
+    ///
+    /// fn message(data: &ApplicationCommandInteractionData) -> Option<&str> {
+    ///     data.named_option("message")?.as_str()
+    /// }
+    /// ```
+    pub fn named_option(&self, name: &str) -> Option<NamedOption<'_>> {
+        find_option(&self.options, name).map(NamedOption)
+    }
+
+    /// Gets the name of the invoked sub-command group, if any.
+    pub fn subcommand_group_name(&self) -> Option<&str> {
+        self.subcommand_group().map(|group| group.name.as_str())
+    }
+
+    /// Gets the name of the invoked sub-command, if any, including one
+    /// nested inside of a sub-command group.
+    pub fn subcommand_name(&self) -> Option<&str> {
+        self.subcommand().map(|subcommand| subcommand.name.as_str())
+    }
+
+    /// Gets the options given to the invoked sub-command, or `None` if no
+    /// sub-command was invoked.
+    pub fn subcommand_options(&self) -> Option<&[ApplicationCommandInteractionDataOption]> {
+        self.subcommand().map(|subcommand| subcommand.options.as_slice())
+    }
+
+    fn subcommand_group(&self) -> Option<&ApplicationCommandInteractionDataOption> {
+        self.options
+            .iter()
+            .find(|option| option.kind == ApplicationCommandOptionType::SubCommandGroup)
+    }
+
+    fn subcommand(&self) -> Option<&ApplicationCommandInteractionDataOption> {
+        let options = match self.subcommand_group() {
+            Some(group) => &group.options,
+            None => &self.options,
+        };
+
+        options.iter().find(|option| option.kind == ApplicationCommandOptionType::SubCommand)
+    }
+
+    /// Gets the top-level option with the given name.
+    ///
+    /// See [`Self::find_option_recursive`] to also look up the options nested
+    /// inside of sub-commands and sub-command groups.
+    pub fn find_option(&self, name: &str) -> Option<&ApplicationCommandInteractionDataOption> {
+        self.options.iter().find(|option| option.name == name)
+    }
+
+    /// Gets the option with the given name, including the options nested
+    /// inside of sub-commands and sub-command groups.
+    ///
+    /// Options are searched depth-first, so the first match in the order
+    /// Discord sent them is returned.
+    pub fn find_option_recursive(
+        &self,
+        name: &str,
+    ) -> Option<&ApplicationCommandInteractionDataOption> {
+        find_option(&self.options, name)
+    }
+
+    /// Gets a resolved user by their Id.
+    pub fn get_resolved_user(&self, user_id: impl Into<UserId>) -> Option<&User> {
+        self.resolved.users.get(&user_id.into())
+    }
+
+    /// Gets a resolved member by their user Id.
+    pub fn get_resolved_member(&self, user_id: impl Into<UserId>) -> Option<&PartialMember> {
+        self.resolved.members.get(&user_id.into())
+    }
+
+    /// Gets a resolved role by its Id.
+    pub fn get_resolved_role(&self, role_id: impl Into<RoleId>) -> Option<&Role> {
+        self.resolved.roles.get(&role_id.into())
+    }
+
+    /// Gets a resolved message by its Id.
+    pub fn get_resolved_message(&self, message_id: impl Into<MessageId>) -> Option<&Message> {
+        self.resolved.messages.get(&message_id.into())
+    }
+
+    /// Gets a resolved channel by its Id.
+    pub fn get_resolved_channel(
+        &self,
+        channel_id: impl Into<ChannelId>,
+    ) -> Option<&PartialChannel> {
+        self.resolved.channels.get(&channel_id.into())
+    }
+
+    /// Creates an [`OptionMap`] to look up the top-level options by name.
+    pub fn option_map(&self) -> OptionMap<'_> {
+        OptionMap::new(&self.options)
+    }
+
+    /// Iterates over the options holding values, including the ones nested
+    /// inside of sub-commands and sub-command groups.
+    ///
+    /// The sub-commands and sub-command groups themselves are skipped. See
+    /// [`Self::all_options_iter`] for the traversal order.
+    pub fn options_iter(&self) -> OptionsIter<'_> {
+        OptionsIter(self.all_options_iter())
+    }
+
+    /// Iterates over all options, including sub-commands, sub-command groups
+    /// and the options nested inside of them.
+    ///
+    /// Options are traversed depth-first in the order Discord sent them, and
+    /// sub-commands and sub-command groups are yielded before the options
+    /// they contain.
+    pub fn all_options_iter(&self) -> AllOptionsIter<'_> {
+        AllOptionsIter {
+            stack: vec![self.options.iter()],
+        }
+    }
+
+    fn resolved_option(&self, name: &str) -> Result<&ApplicationCommandInteractionDataOptionValue> {
+        let option = find_option(&self.options, name)
+            .ok_or_else(|| Error::Model(ModelError::OptionNotFound(name.to_string())))?;
+
+        option
+            .resolved
+            .as_ref()
+            .ok_or_else(|| Error::Model(ModelError::InvalidOptionType(name.to_string())))
+    }
+}
+
+impl OptionExtract for ApplicationCommandInteractionData {
+    fn string(&self, name: &str) -> Result<String> {
+        match self.resolved_option(name)? {
+            ApplicationCommandInteractionDataOptionValue::String(value) => Ok(value.clone()),
+            _ => Err(Error::Model(ModelError::InvalidOptionType(name.to_string()))),
+        }
+    }
+
+    fn integer(&self, name: &str) -> Result<i64> {
+        match self.resolved_option(name)? {
+            ApplicationCommandInteractionDataOptionValue::Integer(value) => Ok(*value),
+            _ => Err(Error::Model(ModelError::InvalidOptionType(name.to_string()))),
+        }
+    }
+
+    fn boolean(&self, name: &str) -> Result<bool> {
+        match self.resolved_option(name)? {
+            ApplicationCommandInteractionDataOptionValue::Boolean(value) => Ok(*value),
+            _ => Err(Error::Model(ModelError::InvalidOptionType(name.to_string()))),
+        }
+    }
+
+    fn user(&self, name: &str) -> Result<(&User, Option<&PartialMember>)> {
+        match self.resolved_option(name)? {
+            ApplicationCommandInteractionDataOptionValue::User(user, member) => {
+                Ok((user, member.as_ref()))
+            },
+            _ => Err(Error::Model(ModelError::InvalidOptionType(name.to_string()))),
+        }
+    }
+
+    fn role(&self, name: &str) -> Result<&Role> {
+        match self.resolved_option(name)? {
+            ApplicationCommandInteractionDataOptionValue::Role(role) => Ok(role),
+            _ => Err(Error::Model(ModelError::InvalidOptionType(name.to_string()))),
+        }
+    }
+
+    fn channel(&self, name: &str) -> Result<&PartialChannel> {
+        match self.resolved_option(name)? {
+            ApplicationCommandInteractionDataOptionValue::Channel(channel) => Ok(channel),
+            _ => Err(Error::Model(ModelError::InvalidOptionType(name.to_string()))),
+        }
+    }
+}
+
+/// Looks up options by name without allocating for small numbers of options.
+///
+/// Up to [`threshold`] options are searched linearly. For more options, an
+/// index is built on the first lookup and reused by later ones.
+///
+/// Only the given options are searched, use [`OptionMap::new`] with the
+/// `options` of a sub-command to look up the options nested inside of it.
+///
+/// [`threshold`]: Self::with_threshold
+#[derive(Clone, Debug)]
+pub struct OptionMap<'a> {
+    options: &'a [ApplicationCommandInteractionDataOption],
+    threshold: usize,
+    index: RefCell<Option<HashMap<&'a str, &'a ApplicationCommandInteractionDataOption>>>,
+}
+
+impl<'a> OptionMap<'a> {
+    /// The default number of options up to which they are searched linearly.
+    pub const DEFAULT_THRESHOLD: usize = 10;
+
+    /// Creates a map over the given options.
+    pub fn new(options: &'a [ApplicationCommandInteractionDataOption]) -> Self {
+        Self {
+            options,
+            threshold: Self::DEFAULT_THRESHOLD,
+            index: RefCell::new(None),
+        }
+    }
+
+    /// Sets the number of options up to which they are searched linearly.
+    ///
+    /// Defaults to [`Self::DEFAULT_THRESHOLD`].
+    pub fn with_threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Gets the option with the given name, if there is one.
+    pub fn get(&self, name: &str) -> Option<&'a ApplicationCommandInteractionDataOption> {
+        if self.options.len() <= self.threshold {
+            return self.options.iter().find(|option| option.name == name);
+        }
+
+        let mut index = self.index.borrow_mut();
+        let index = index.get_or_insert_with(|| {
+            self.options.iter().map(|option| (option.name.as_str(), option)).collect()
+        });
+
+        index.get(name).copied()
+    }
+
+    /// Whether there is an option with the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// The number of options.
+    pub fn len(&self) -> usize {
+        self.options.len()
+    }
+
+    /// Whether there are no options.
+    pub fn is_empty(&self) -> bool {
+        self.options.is_empty()
+    }
+}
+
+/// An iterator over all options of an [`ApplicationCommandInteractionData`].
+///
+/// Created with [`ApplicationCommandInteractionData::all_options_iter`].
+#[derive(Clone, Debug)]
+pub struct AllOptionsIter<'a> {
+    stack: Vec<std::slice::Iter<'a, ApplicationCommandInteractionDataOption>>,
+}
+
+impl<'a> Iterator for AllOptionsIter<'a> {
+    type Item = &'a ApplicationCommandInteractionDataOption;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let options = self.stack.last_mut()?;
+
+            match options.next() {
+                Some(option) => {
+                    self.stack.push(option.options.iter());
+
+                    return Some(option);
+                },
+                None => {
+                    self.stack.pop();
+                },
+            }
+        }
+    }
+}
+
+/// An iterator over the options holding values of an
+/// [`ApplicationCommandInteractionData`].
+///
+/// Created with [`ApplicationCommandInteractionData::options_iter`].
+#[derive(Clone, Debug)]
+pub struct OptionsIter<'a>(AllOptionsIter<'a>);
+
+impl<'a> Iterator for OptionsIter<'a> {
+    type Item = &'a ApplicationCommandInteractionDataOption;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.by_ref().find(|option| {
+            !matches!(
+                option.kind,
+                ApplicationCommandOptionType::SubCommand
+                    | ApplicationCommandOptionType::SubCommandGroup
+            )
+        })
+    }
+}
+
+/// A typed view of an [`ApplicationCommandInteractionDataOption`].
+///
+/// Created with [`ApplicationCommandInteractionData::named_option`]. Each
+/// accessor returns `None` if the option is of a different type.
+#[derive(Clone, Copy, Debug)]
+pub struct NamedOption<'a>(&'a ApplicationCommandInteractionDataOption);
+
+impl<'a> NamedOption<'a> {
+    /// Gets the name of the option.
+    pub fn name(&self) -> &'a str {
+        &self.0.name
+    }
+
+    /// Gets the underlying option.
+    pub fn option(&self) -> &'a ApplicationCommandInteractionDataOption {
+        self.0
+    }
+
+    /// Gets the value of a [`String`] option.
+    ///
+    /// [`String`]: ApplicationCommandOptionType::String
+    pub fn as_str(&self) -> Option<&'a str> {
+        self.0.as_string()
+    }
+
+    /// Gets the value of an [`Integer`] option.
+    ///
+    /// [`Integer`]: ApplicationCommandOptionType::Integer
+    pub fn as_i64(&self) -> Option<i64> {
+        self.0.as_i64()
+    }
+
+    /// Gets the value of a [`Number`] option.
+    ///
+    /// [`Number`]: ApplicationCommandOptionType::Number
+    pub fn as_f64(&self) -> Option<f64> {
+        self.0.as_f64()
+    }
+
+    /// Gets the value of a [`Boolean`] option.
+    ///
+    /// [`Boolean`]: ApplicationCommandOptionType::Boolean
+    pub fn as_bool(&self) -> Option<bool> {
+        self.0.as_bool()
+    }
+
+    /// Gets the user of a [`User`] option, along with their member data if
+    /// the command was used in a guild.
+    ///
+    /// [`User`]: ApplicationCommandOptionType::User
+    pub fn as_user(&self) -> Option<(&'a User, Option<&'a PartialMember>)> {
+        self.0.as_user()
+    }
+
+    /// Gets the role of a [`Role`] option.
+    ///
+    /// [`Role`]: ApplicationCommandOptionType::Role
+    pub fn as_role(&self) -> Option<&'a Role> {
+        self.0.as_role()
+    }
+
+    /// Gets the channel of a [`Channel`] option.
+    ///
+    /// [`Channel`]: ApplicationCommandOptionType::Channel
+    pub fn as_channel(&self) -> Option<&'a PartialChannel> {
+        self.0.as_channel()
+    }
+}
+
+/// Re-exports of the traits used for working with interactions.
+///
+/// ```rust,no_run
+/// use serenity::model::interactions::prelude::*;
+/// ```
+pub mod prelude {
+    pub use super::OptionExtract;
+}
+
+impl CommandId {
+    /// Formats a mention of the command with the given `name`, which makes the
+    /// Discord client render it as a clickable command.
+    ///
+    /// The `name` may also include a sub-command group and sub-command, such as
+    /// `"tag get"`.
+    ///
+    /// **Note**: This is different from the [`Display`] implementation, which
+    /// only writes the numeric Id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::id::CommandId;
+    ///
+    /// let id = CommandId(123);
+    ///
+    /// assert_eq!(id.to_mention("ping"), "</ping:123>");
+    /// assert_eq!(id.to_string(), "123");
+    /// ```
+    ///
+    /// [`Display`]: std::fmt::Display
+    pub fn to_mention(&self, name: &str) -> String {
+        format!("</{}:{}>", name, self.0)
+    }
+}
+
+impl CommandPermissionId {
+    /// Converts this [`CommandPermissionId`] to [`UserId`].
+    pub fn to_user_id(self) -> UserId {
+        UserId(self.0)
+    }
+
+    /// Converts this [`CommandPermissionId`] to [`RoleId`].
+    pub fn to_role_id(self) -> RoleId {
+        RoleId(self.0)
+    }
+}
+
+impl From<RoleId> for CommandPermissionId {
+    fn from(id: RoleId) -> Self {
+        Self(id.0)
+    }
+}
+
+impl From<UserId> for CommandPermissionId {
+    fn from(id: UserId) -> Self {
+        Self(id.0)
+    }
+}
+
+impl From<CommandPermissionId> for RoleId {
+    fn from(id: CommandPermissionId) -> Self {
+        Self(id.0)
+    }
+}
+
+impl From<CommandPermissionId> for UserId {
+    fn from(id: CommandPermissionId) -> Self {
+        Self(id.0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use serde_json::json;
+
+    use super::ApplicationCommandInteractionDataOptionValue as OptionValue;
+    use crate::model::prelude::*;
+
+    fn round_trip(value: &OptionValue) -> OptionValue {
+        serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_option_value_primitives() {
+        let value = OptionValue::String("hello".to_string());
+        assert_eq!(serde_json::to_value(&value).unwrap(), json!("hello"));
+        assert!(matches!(round_trip(&value), OptionValue::String(s) if s == "hello"));
+
+        let value = OptionValue::Integer(-42);
+        assert_eq!(serde_json::to_value(&value).unwrap(), json!(-42));
+        assert!(matches!(round_trip(&value), OptionValue::Integer(-42)));
+
+        let value = OptionValue::Number(1.5);
+        assert_eq!(serde_json::to_value(&value).unwrap(), json!(1.5));
+        assert_eq!(round_trip(&value).as_number(), Some(1.5));
+
+        let value = OptionValue::Boolean(true);
+        assert_eq!(serde_json::to_value(&value).unwrap(), json!(true));
+        assert!(matches!(round_trip(&value), OptionValue::Boolean(true)));
+    }
+
+    #[test]
+    fn test_option_value_primitive_accessors() {
+        let value = OptionValue::String("hello".to_string());
+        assert_eq!(value.as_str(), Some("hello"));
+        assert_eq!(value.as_i64(), None);
+
+        let value = OptionValue::Integer(-42);
+        assert_eq!(value.as_i64(), Some(-42));
+        assert_eq!(value.as_f64(), None);
+
+        let value = OptionValue::Number(1.5);
+        assert_eq!(value.as_f64(), Some(1.5));
+        assert_eq!(value.as_bool(), None);
+
+        let value = OptionValue::Boolean(true);
+        assert_eq!(value.as_bool(), Some(true));
+        assert_eq!(value.as_str(), None);
+        assert!(value.as_user().is_none());
+        assert!(value.as_role().is_none());
+        assert!(value.as_channel().is_none());
+    }
+
+    #[test]
+    fn test_option_value_user() {
+        let user: User = serde_json::from_value(json!({
+            "id": "1",
+            "avatar": null,
+            "bot": false,
+            "discriminator": 1234,
+            "username": "user",
+            "public_flags": null,
+        }))
+        .unwrap();
+
+        let value = OptionValue::User(user.clone(), None);
+        assert!(matches!(value.as_user(), Some((u, None)) if u.id == UserId(1)));
+        assert!(value.as_str().is_none());
+        assert_eq!(serde_json::to_value(&value).unwrap(), serde_json::to_value(&user).unwrap());
+        assert!(matches!(round_trip(&value), OptionValue::User(u, None) if u.id == UserId(1)));
+
+        let member: PartialMember = serde_json::from_value(json!({
+            "joined_at": null,
+            "nick": "nick",
+            "roles": [],
+            "premium_since": null,
+            "guild_id": "2",
+        }))
+        .unwrap();
+
+        let value = OptionValue::User(user, Some(member));
+        let serialized = serde_json::to_value(&value).unwrap();
+        assert_eq!(serialized["username"], "user");
+        assert_eq!(serialized["member"]["nick"], "nick");
+        assert!(matches!(
+            round_trip(&value),
+            OptionValue::User(u, Some(m)) if u.id == UserId(1) && m.nick.as_deref() == Some("nick")
+        ));
+    }
+
+    #[test]
+    fn test_option_value_channel_and_role() {
+        let channel: PartialChannel = serde_json::from_value(json!({
+            "id": "3",
+            "name": "general",
+            "type": 0,
+            "permissions": "8",
+        }))
+        .unwrap();
+
+        let value = OptionValue::Channel(channel.clone());
+        assert_eq!(value.as_channel().map(|c| c.id), Some(ChannelId(3)));
+        assert!(value.as_role().is_none());
+        assert_eq!(
+            serde_json::to_value(&value).unwrap(),
+            serde_json::to_value(&channel).unwrap()
+        );
+        assert!(matches!(round_trip(&value), OptionValue::Channel(c) if c.id == ChannelId(3)));
+
+        let role_json = json!({
+            "id": "4",
+            "guild_id": "2",
+            "color": 0,
+            "hoist": false,
+            "managed": false,
+            "mentionable": false,
+            "name": "role",
+            "permissions": "0",
+            "position": 1,
+        });
+        let role: Role = serde_json::from_value(role_json.clone()).unwrap();
+
+        let value = OptionValue::Role(role.clone());
+        assert_eq!(value.as_role().map(|r| r.id), Some(RoleId(4)));
+        assert!(value.as_channel().is_none());
+        assert_eq!(serde_json::to_value(&value).unwrap(), serde_json::to_value(&role).unwrap());
+        assert!(matches!(
+            serde_json::from_value(role_json).unwrap(),
+            OptionValue::Role(r) if r.id == RoleId(4)
+        ));
+    }
+
+    #[test]
+    fn test_option_extract() {
+        use super::prelude::*;
+
+        let data = sub_command_data();
+
+        assert_eq!(data.string("name").unwrap(), "rust");
+        assert_eq!(data.integer("limit").unwrap(), 5);
+        assert!(data.boolean("raw").unwrap());
+        assert!(matches!(
+            data.integer("name"),
+            Err(Error::Model(ModelError::InvalidOptionType(name))) if name == "name"
+        ));
+        assert!(matches!(
+            data.role("missing"),
+            Err(Error::Model(ModelError::OptionNotFound(name))) if name == "missing"
+        ));
+    }
+
+    fn sub_command_data() -> ApplicationCommandInteractionData {
+        serde_json::from_value(json!({
+            "id": "1",
+            "name": "tag",
+            "options": [{
+                "name": "get",
+                "type": 1,
+                "options": [
+                    {"name": "name", "type": 3, "value": "rust"},
+                    {"name": "limit", "type": 4, "value": 5},
+                    {"name": "raw", "type": 5, "value": true},
+                ],
+            }],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_options_iter() {
+        let data = sub_command_data();
+
+        let names: Vec<_> = data.all_options_iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, ["get", "name", "limit", "raw"]);
+        let names: Vec<_> = data.options_iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, ["name", "limit", "raw"]);
+    }
+
+    #[test]
+    fn test_option_map() {
+        let data = sub_command_data();
+
+        let get = data.option_map().get("get").unwrap();
+        let options = OptionMap::new(&get.options);
+        assert_eq!(options.get("limit").and_then(|o| o.value.as_ref()), Some(&json!(5)));
+        let options = options.with_threshold(0);
+        assert_eq!(options.get("raw").map(|o| o.kind), Some(ApplicationCommandOptionType::Boolean));
+        assert!(!options.contains("missing"));
+    }
+
+    #[test]
+    fn test_named_option() {
+        let data: ApplicationCommandInteractionData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "say",
+            "options": [
+                {"name": "message", "type": 3, "value": "hello"},
+                {"name": "volume", "type": 10, "value": 0.5},
+            ],
+        }))
+        .unwrap();
+
+        let message = data.named_option("message").unwrap();
+        assert_eq!(message.name(), "message");
+        assert_eq!(message.as_str(), Some("hello"));
+        assert_eq!(message.as_i64(), None);
+        assert_eq!(data.named_option("volume").and_then(|o| o.as_f64()), Some(0.5));
+        assert!(data.named_option("missing").is_none());
+    }
+
+    #[test]
+    fn test_option_value_accessors() {
+        let data: ApplicationCommandInteractionData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "say",
+            "options": [
+                {"name": "message", "type": 3, "value": "hello"},
+                {"name": "volume", "type": 10, "value": 0.5},
+                {"name": "loud", "type": 5, "value": true},
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(data.options[0].as_string(), Some("hello"));
+        assert!(data.options[0].as_i64().is_none());
+        assert_eq!(data.options[1].as_f64(), Some(0.5));
+        assert!(data.options[1].as_bool().is_none());
+        assert_eq!(data.options[2].as_bool(), Some(true));
+        assert!(data.options[2].as_string().is_none());
+    }
+
+    #[test]
+    fn test_callback_data_flags() {
+        let flags = InteractionApplicationCommandCallbackDataFlags::EPHEMERAL
+            | InteractionApplicationCommandCallbackDataFlags::SUPPRESS_EMBEDS;
+
+        assert_eq!(serde_json::to_value(flags).unwrap(), json!(68));
+        assert_eq!(
+            serde_json::from_value::<InteractionApplicationCommandCallbackDataFlags>(json!(68))
+                .unwrap(),
+            flags
+        );
+    }
+
+    #[test]
+    fn test_resolved_messages() {
+        let data: ApplicationCommandInteractionData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "Quote",
+            "type": 3,
+            "resolved": {
+                "messages": {
+                    "2": {
+                        "id": "2",
+                        "attachments": [],
+                        "author": {
+                            "id": "3",
+                            "username": "user",
+                            "discriminator": "0001",
+                            "avatar": null,
+                        },
+                        "channel_id": "4",
+                        "content": "hello",
+                        "edited_timestamp": null,
+                        "embeds": [],
+                        "type": 0,
+                        "mention_everyone": false,
+                        "mention_roles": [],
+                        "mentions": [],
+                        "pinned": false,
+                        "timestamp": "2021-01-01T00:00:00+00:00",
+                        "tts": false,
+                    },
+                },
+            },
+        }))
+        .unwrap();
+
+        let message = data.get_resolved_message(2).unwrap();
+        assert_eq!(message.content, "hello");
+
+        let value = OptionValue::Message(message.clone());
+        assert_eq!(value.as_message().map(|m| m.id), Some(MessageId(2)));
+        assert!(matches!(round_trip(&value), OptionValue::Message(m) if m.author.id == UserId(3)));
+    }
+
+    #[test]
+    fn test_subcommand() {
+        let data: ApplicationCommandInteractionData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "tag",
+            "options": [{
+                "name": "admin",
+                "type": 2,
+                "options": [{
+                    "name": "delete",
+                    "type": 1,
+                    "options": [{"name": "name", "type": 3, "value": "rust"}],
+                }],
+            }],
+        }))
+        .unwrap();
+
+        assert_eq!(data.subcommand_group_name(), Some("admin"));
+        assert_eq!(data.subcommand_name(), Some("delete"));
+
+        let options = data.subcommand_options().unwrap();
+        assert_eq!(options.len(), 1);
+        assert_eq!(options[0].name, "name");
+
+        let data: ApplicationCommandInteractionData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "ping",
+            "options": [{"name": "loud", "type": 5, "value": true}],
+        }))
+        .unwrap();
+
+        assert!(data.subcommand_group_name().is_none());
+        assert!(data.subcommand_name().is_none());
+        assert!(data.subcommand_options().is_none());
+    }
+
+    #[cfg(feature = "preserve_order")]
+    #[test]
+    fn test_resolved_order() {
+        let resolved: ApplicationCommandInteractionDataResolved =
+            serde_json::from_value(json!({
+                "users": {
+                    "3": {"id": "3", "username": "c", "discriminator": "0003", "avatar": null},
+                    "1": {"id": "1", "username": "a", "discriminator": "0001", "avatar": null},
+                    "2": {"id": "2", "username": "b", "discriminator": "0002", "avatar": null},
+                },
+            }))
+            .unwrap();
+
+        let ids: Vec<_> = resolved.users.keys().copied().collect();
+        assert_eq!(ids, vec![UserId(3), UserId(1), UserId(2)]);
+    }
+
+    #[test]
+    fn test_find_option() {
+        let data: ApplicationCommandInteractionData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "tag",
+            "options": [{
+                "name": "give",
+                "type": 1,
+                "options": [{"name": "target", "type": 6, "value": "2"}],
+            }],
+            "resolved": {
+                "users": {
+                    "2": {"id": "2", "username": "user", "discriminator": "0001", "avatar": null},
+                },
+            },
+        }))
+        .unwrap();
+
+        assert!(data.find_option("give").is_some());
+        assert!(data.find_option("target").is_none());
+
+        let target = data.find_option_recursive("target").unwrap();
+        assert_eq!(target.value, Some(json!("2")));
+
+        assert_eq!(data.get_resolved_user(2).map(|u| u.name.as_str()), Some("user"));
+        assert!(data.get_resolved_member(2).is_none());
+        assert!(data.get_resolved_user(3).is_none());
+    }
+
+    #[test]
+    fn test_locale() {
+        let mut payload = json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 1,
+            "token": "token",
+            "version": 1,
+        });
+
+        let ping: Interaction = serde_json::from_value(payload.clone()).unwrap();
+        assert_eq!(ping.locale_as_str(), "");
+
+        payload["type"] = json!(3);
+        payload["data"] = json!({"custom_id": "button", "component_type": 2});
+        assert!(serde_json::from_value::<Interaction>(payload.clone()).is_err());
+
+        payload["locale"] = json!("de");
+        payload["guild_locale"] = json!("fr");
+
+        let interaction: Interaction = serde_json::from_value(payload).unwrap();
+        assert_eq!(interaction.locale_as_str(), "de");
+        assert_eq!(interaction.guild_locale(), Some("fr"));
+    }
+
+    #[test]
+    fn test_autocomplete_focused_option() {
+        let interaction: Interaction = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 4,
+            "data": {
+                "id": "3",
+                "name": "search",
+                "options": [
+                    {"name": "limit", "type": 4, "value": 5},
+                    {"name": "query", "type": 4, "value": "12", "focused": true},
+                ],
+            },
+            "token": "token",
+            "version": 1,
+            "locale": "en-US",
+        }))
+        .unwrap();
+
+        assert_eq!(interaction.command_name(), Some("search"));
+
+        let autocomplete = interaction.as_autocomplete().unwrap();
+        let focused = autocomplete.focused_option().unwrap();
+
+        assert_eq!(focused.name, "query");
+        assert_eq!(focused.value, Some(json!("12")));
+        assert!(focused.resolved.is_none());
+    }
+
+    #[test]
+    fn test_component_interaction() {
+        let interaction: Interaction = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 3,
+            "data": {
+                "custom_id": "colour",
+                "component_type": 3,
+                "values": ["red", "blue"],
+            },
+            "token": "token",
+            "version": 1,
+            "locale": "en-US",
+        }))
+        .unwrap();
+
+        assert!(interaction.data.is_none());
+        assert!(interaction.as_autocomplete().is_none());
+
+        assert_eq!(interaction.command_name(), Some("colour"));
+
+        let component = interaction.as_component().unwrap();
+
+        assert_eq!(component.custom_id(), "colour");
+        assert_eq!(component.component_type(), ComponentType::SelectMenu);
+        assert_eq!(component.values(), ["red", "blue"]);
+
+        let data = interaction.component_data.as_ref().unwrap();
+
+        assert!(data.is_select_menu() && !data.is_button());
+        assert_eq!(data.button_custom_id(), None);
+        assert_eq!(data.selected_values(), Some(&["red".to_string(), "blue".to_string()][..]));
+
+        let menu = data.select_menu().unwrap();
+        assert_eq!(menu.custom_id, "colour");
+        assert_eq!(menu.values, ["red", "blue"]);
+    }
+
+    #[test]
+    fn test_component_interaction_round_trip() {
+        let interaction: Interaction = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 3,
+            "data": {"custom_id": "vote", "component_type": 2},
+            "token": "token",
+            "version": 1,
+            "locale": "en-US",
+        }))
+        .unwrap();
+
+        let value = serde_json::to_value(&interaction).unwrap();
+        assert_eq!(value["data"]["custom_id"], json!("vote"));
+
+        let round_trip: Interaction = serde_json::from_value(value).unwrap();
+        assert!(round_trip.data.is_none());
+        assert_eq!(round_trip.component_data.unwrap().custom_id, "vote");
+    }
+
+    #[test]
+    fn test_modal_submit_interaction() {
+        let interaction: Interaction = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 5,
+            "data": {
+                "custom_id": "feedback",
+                "components": [{
+                    "type": 1,
+                    "components": [{"type": 4, "custom_id": "comment", "value": "Nice bot"}],
+                }],
+            },
+            "token": "token",
+            "version": 1,
+            "locale": "en-US",
+        }))
+        .unwrap();
+
+        assert_eq!(interaction.kind, InteractionType::ModalSubmit);
+        assert!(interaction.data.is_none() && interaction.component_data.is_none());
+        assert_eq!(interaction.command_name(), Some("feedback"));
+
+        let data = interaction.modal_data.as_ref().unwrap();
+        let input = &data.components[0].components[0];
+
+        assert_eq!(input.kind, ComponentType::InputText);
+        assert_eq!(input.custom_id, "comment");
+        assert_eq!(input.value, "Nice bot");
+        assert_eq!(data.get_field("comment"), Some("Nice bot"));
+        assert_eq!(data.get_field("rating"), None);
+        assert_eq!(data.fields().len(), 1);
+    }
+
+    #[test]
+    fn test_modal_submit_interaction_round_trip() {
+        let interaction: Interaction = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 5,
+            "data": {
+                "custom_id": "feedback",
+                "components": [{
+                    "type": 1,
+                    "components": [{"type": 4, "custom_id": "comment", "value": "Nice bot"}],
+                }],
+            },
+            "token": "token",
+            "version": 1,
+            "locale": "en-US",
+        }))
+        .unwrap();
+
+        let value = serde_json::to_value(&interaction).unwrap();
+        assert_eq!(value["data"]["custom_id"], json!("feedback"));
+
+        let round_trip: Interaction = serde_json::from_value(value).unwrap();
+        assert!(round_trip.data.is_none());
+        assert_eq!(round_trip.modal_data.unwrap().get_field("comment"), Some("Nice bot"));
+    }
+
+    #[test]
+    fn test_installed_by_user() {
+        let mut payload = json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 1,
+            "token": "token",
+            "version": 1,
+            "user": {"id": "3", "username": "user", "discriminator": "0001", "avatar": null},
+            "authorizing_integration_owners": {"1": "3"},
+        });
+
+        let interaction: Interaction = serde_json::from_value(payload.clone()).unwrap();
+
+        assert_eq!(
+            interaction
+                .authorizing_integration_owners
+                .get(&ApplicationIntegrationType::UserInstall),
+            Some(&3)
+        );
+        assert!(interaction.installed_by_user());
+
+        let round_trip: Interaction =
+            serde_json::from_value(serde_json::to_value(&interaction).unwrap()).unwrap();
+        let owners = &interaction.authorizing_integration_owners;
+        assert_eq!(&round_trip.authorizing_integration_owners, owners);
+
+        payload["authorizing_integration_owners"] = json!({"0": "4"});
+        let interaction: Interaction = serde_json::from_value(payload).unwrap();
+
+        assert!(!interaction.installed_by_user());
+    }
+
+    #[test]
+    fn test_entitlements() {
+        let interaction: Interaction = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 1,
+            "token": "token",
+            "version": 1,
+            "entitlements": [
+                {"id": "3", "sku_id": "4", "application_id": "2", "user_id": "5"},
+                {
+                    "id": "6",
+                    "sku_id": "7",
+                    "application_id": "2",
+                    "user_id": "5",
+                    "ends_at": "2020-01-01T00:00:00+00:00",
+                },
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(interaction.entitlements.len(), 2);
+        assert!(interaction.has_entitlement(SkuId(4)));
+        assert!(!interaction.has_entitlement(SkuId(7)));
+        assert!(!interaction.has_entitlement(SkuId(8)));
+    }
+
+    #[test]
+    fn test_deserialize_with_guild_id() {
+        let payload = json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 2,
+            "data": {
+                "id": "3",
+                "name": "role",
+                "options": [{"name": "role", "type": 8, "value": "4"}],
+                "resolved": {
+                    "roles": {
+                        "4": {
+                            "id": "4",
+                            "color": 0,
+                            "hoist": false,
+                            "managed": false,
+                            "mentionable": false,
+                            "name": "role",
+                            "permissions": "0",
+                            "position": 1,
+                        },
+                    },
+                },
+            },
+            "token": "token",
+            "version": 1,
+            "locale": "en-US",
+        });
+
+        assert!(serde_json::from_value::<Interaction>(payload.clone()).is_err());
+
+        let interaction =
+            super::deserialize_interaction_with_guild_id(payload, Some(GuildId(5))).unwrap();
+
+        assert_eq!(interaction.guild_id, Some(GuildId(5)));
+        assert_eq!(interaction.data.unwrap().resolved.roles[&RoleId(4)].guild_id, GuildId(5));
+    }
+
+    #[test]
+    fn test_mentionable_option() {
+        let data: ApplicationCommandInteractionData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "mention",
+            "options": [
+                {"name": "user", "type": 9, "value": "2"},
+                {"name": "role", "type": 9, "value": "4"},
+            ],
+            "resolved": {
+                "users": {
+                    "2": {"id": "2", "username": "ab", "discriminator": "0001", "avatar": null},
+                },
+                "roles": {
+                    "4": {
+                        "id": "4",
+                        "guild_id": "5",
+                        "color": 0,
+                        "hoist": false,
+                        "managed": false,
+                        "mentionable": true,
+                        "name": "role",
+                        "permissions": "0",
+                        "position": 1,
+                    },
+                },
+            },
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            &data.options[0].resolved,
+            Some(OptionValue::User(u, None)) if u.id == UserId(2)
+        ));
+        assert!(matches!(
+            &data.options[1].resolved,
+            Some(OptionValue::Role(r)) if r.id == RoleId(4)
+        ));
+    }
+
+    #[test]
+    fn test_attachment_option() {
+        let data: ApplicationCommandInteractionData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "upload",
+            "options": [{"name": "file", "type": 11, "value": "2"}],
+            "resolved": {
+                "attachments": {
+                    "2": {
+                        "id": "2",
+                        "filename": "cat.png",
+                        "description": "A cat",
+                        "size": 1024,
+                        "url": "https://cdn.discordapp.com/cat.png",
+                        "proxy_url": "https://media.discordapp.net/cat.png",
+                    },
+                },
+            },
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            &data.options[0].resolved,
+            Some(OptionValue::Attachment(a)) if a.filename == "cat.png"
+                && a.description.as_deref() == Some("A cat")
+        ));
+    }
+
+    #[test]
+    fn test_deadline_watcher() {
+        let old = TokenDeadlineWatcher::new(InteractionId(1));
+        assert!(old.is_late());
+        assert_eq!(old.remaining(), std::time::Duration::from_secs(0));
+
+        let millis = chrono::Utc::now().timestamp_millis() as u64 - 1_420_070_400_000;
+        let new = TokenDeadlineWatcher::new(InteractionId(millis << 22));
+        assert!(!new.is_late());
+        assert!(new.remaining() > std::time::Duration::from_secs(2));
+    }
+
+    fn pick_command(id: u64, description: &str) -> ApplicationCommand {
+        serde_json::from_value(json!({
+            "id": id.to_string(),
+            "application_id": "2",
+            "name": "pick",
+            "description": description,
+            "options": [{
+                "type": 3,
+                "name": "kind",
+                "description": "The kind of animal",
+                "choices": [{"name": "Cat", "value": "cat"}],
+            }],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_command_eq() {
+        assert_eq!(pick_command(1, "Picks an animal"), pick_command(1, "Picks a pet"));
+        assert_ne!(pick_command(1, "Picks an animal"), pick_command(3, "Picks an animal"));
+        assert!(pick_command(1, "Picks an animal") < pick_command(3, "Picks an animal"));
+    }
+
+    #[test]
+    fn test_definition_hash() {
+        let hash = pick_command(1, "Picks an animal").definition_hash();
+        assert_eq!(hash, pick_command(3, "Picks an animal").definition_hash());
+        assert_ne!(hash, pick_command(1, "Picks a pet").definition_hash());
+    }
+
+    #[test]
+    fn test_definition_hash_fields() {
+        let base = json!({
+            "id": "1",
+            "application_id": "2",
+            "name": "pick",
+            "description": "Picks an animal",
+            "options": [{
+                "type": 7,
+                "name": "kind",
+                "description": "The kind of animal",
+                "choices": [{"name": "Cat", "value": "cat"}],
+            }],
+        });
+        let hash = |value: &Value| {
+            serde_json::from_value::<ApplicationCommand>(value.clone()).unwrap().definition_hash()
+        };
+        let base_hash = hash(&base);
+
+        let cases = vec![
+            ("/name_localizations", json!({"de": "wahl"})),
+            ("/description_localizations", json!({"de": "Tier"})),
+            ("/default_member_permissions", json!("8")),
+            ("/type", json!(2)),
+            ("/dm_permission", json!(false)),
+            ("/integration_types", json!([1])),
+            ("/contexts", json!([1])),
+            ("/nsfw", json!(true)),
+            ("/options/0/name_localizations", json!({"de": "art"})),
+            ("/options/0/description_localizations", json!({"de": "Art"})),
+            ("/options/0/autocomplete", json!(true)),
+            ("/options/0/channel_types", json!([0])),
+            ("/options/0/min_value", json!(1)),
+            ("/options/0/max_value", json!(5)),
+            ("/options/0/choices/0/name_localizations", json!({"de": "Katze"})),
+        ];
+
+        for (pointer, value) in cases {
+            let (parent, field) = pointer.split_at(pointer.rfind('/').unwrap());
+            let mut changed = base.clone();
+            let object = changed.pointer_mut(parent).unwrap().as_object_mut().unwrap();
+            object.insert(field[1..].to_string(), value);
+
+            assert_ne!(hash(&changed), base_hash, "{} is not hashed", pointer);
+        }
+
+        // The same localizations on a choice and on its option must not be
+        // mistaken for one another.
+        let mut on_choice = base.clone();
+        on_choice["options"][0]["choices"][0]["name_localizations"] = json!({"de": "Katze"});
+        let mut on_option = base.clone();
+        on_option["options"][0]["name_localizations"] = json!({"de": "Katze"});
+
+        assert_ne!(hash(&on_choice), hash(&on_option));
+    }
+
+    #[test]
+    fn test_safe_token_redacted() {
+        let token: SafeToken = serde_json::from_value(json!("secret")).unwrap();
+
+        assert_eq!(token.expose(), "secret");
+        assert_eq!(token.to_string(), "<redacted>");
+        assert_eq!(format!("{:?}", token), "SafeToken(<redacted>)");
+        assert_eq!(serde_json::to_value(&token).unwrap(), json!("secret"));
+    }
+
+    #[test]
+    fn test_app_permissions() {
+        let interaction: Interaction = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 1,
+            "token": "token",
+            "version": 1,
+            "app_permissions": "2048",
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            interaction.channel_id_or_err(),
+            Err(Error::Model(ModelError::InvalidChannelInteraction))
+        ));
+        assert!(interaction.bot_can(Permissions::SEND_MESSAGES));
+        assert!(!interaction.bot_can(Permissions::SEND_MESSAGES | Permissions::ADMINISTRATOR));
+    }
+
+    #[test]
+    fn test_command_permission_data() {
+        let data: ApplicationCommandPermissionData =
+            serde_json::from_value(json!({"id": "1", "type": 1, "permission": false})).unwrap();
+
+        assert!(data.denies() && !data.allows());
+        assert!(data.applies_to_role(RoleId(1)));
+        assert!(!data.applies_to_user(UserId(1)));
+        assert!(!data.applies_to_role(RoleId(2)));
+    }
+
+    #[test]
+    fn test_permission_type_display() {
+        let kind = ApplicationCommandPermissionType::Role;
+
+        assert_eq!(kind.to_string(), "role");
+        assert_eq!(kind.to_string().parse::<ApplicationCommandPermissionType>().ok(), Some(kind));
+        assert!(matches!("USER".parse(), Ok(ApplicationCommandPermissionType::User)));
+        assert!("channel".parse::<ApplicationCommandPermissionType>().is_err());
+    }
+
+    #[test]
+    fn test_type_from_str() {
+        assert!(matches!("ping".parse(), Ok(InteractionType::Ping)));
+        assert!(matches!("ModalSubmit".parse(), Ok(InteractionType::ModalSubmit)));
+        assert!("command".parse::<InteractionType>().is_err());
+
+        let group = "SubCommandGroup".parse::<ApplicationCommandOptionType>();
+        assert!(matches!(group, Ok(ApplicationCommandOptionType::SubCommandGroup)));
+        assert!(matches!("NUMBER".parse(), Ok(ApplicationCommandOptionType::Number)));
+        assert!("float".parse::<ApplicationCommandOptionType>().is_err());
+    }
+}
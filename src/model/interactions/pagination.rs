@@ -0,0 +1,230 @@
+//! Responding to interactions with lists spread over several pages.
+//!
+//! A [`PaginatedResponse`] shows one page of items in an embed, along with
+//! Previous and Next buttons. Clicking a button sends a message component
+//! interaction with the [`PREVIOUS_ID`] or [`NEXT_ID`] custom Id, which is
+//! passed back to [`PaginatedResponse::update_page`] to show the other page.
+
+use std::fmt::Display;
+
+use super::{ButtonStyle, Interaction, InteractionResponseType};
+use crate::builder::{CreateActionRow, CreateInteractionResponseData};
+use crate::http::Http;
+use crate::internal::prelude::*;
+
+/// The custom Id of the button showing the previous page.
+pub const PREVIOUS_ID: &str = "serenity_paginated_previous";
+
+/// The custom Id of the button showing the next page.
+pub const NEXT_ID: &str = "serenity_paginated_next";
+
+/// The direction to move the page of a [`PaginatedResponse`] in.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum PageDirection {
+    Previous,
+    Next,
+}
+
+impl PageDirection {
+    /// Gets the direction of the button with the given custom Id, or `None`
+    /// if it is not one of the buttons of a [`PaginatedResponse`].
+    pub fn from_custom_id(custom_id: &str) -> Option<Self> {
+        match custom_id {
+            PREVIOUS_ID => Some(PageDirection::Previous),
+            NEXT_ID => Some(PageDirection::Next),
+            _ => None,
+        }
+    }
+}
+
+/// A list of items shown one page at a time.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::interactions::Interaction;
+/// use serenity::model::interactions::pagination::{PageDirection, PaginatedResponse};
+///
+/// # async fn run(http: &Http, command: Interaction, click: Interaction) -> serenity::Result<()> {
+/// let mut pages = PaginatedResponse::new((1..=50).collect::<Vec<u32>>(), 10);
+/// pages.title("Numbers");
+///
+/// pages.send(&command, http).await?;
+///
+/// // Later, when a button of the response is clicked:
+/// let component = click.as_component().expect("A button was clicked");
+///
+/// if let Some(direction) = PageDirection::from_custom_id(component.custom_id()) {
+///     pages.update_page(&click, http, direction).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PaginatedResponse<T> {
+    items: Vec<T>,
+    page_size: usize,
+    page: usize,
+    title: Option<String>,
+}
+
+impl<T: Display> PaginatedResponse<T> {
+    /// Creates a response showing `page_size` items per page, starting at the
+    /// first page.
+    ///
+    /// **Note**: A `page_size` of 0 is treated as 1.
+    pub fn new(items: Vec<T>, page_size: usize) -> Self {
+        Self {
+            items,
+            page_size: page_size.max(1),
+            page: 0,
+            title: None,
+        }
+    }
+
+    /// Sets the title of the embed.
+    pub fn title(&mut self, title: impl ToString) -> &mut Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Gets the index of the current page, starting at 0.
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Gets the number of pages, which is at least 1.
+    pub fn page_count(&self) -> usize {
+        match self.items.len() {
+            0 => 1,
+            len => (len - 1) / self.page_size + 1,
+        }
+    }
+
+    /// Gets the items of the current page.
+    pub fn current_items(&self) -> &[T] {
+        let start = (self.page * self.page_size).min(self.items.len());
+        let end = (start + self.page_size).min(self.items.len());
+
+        &self.items[start..end]
+    }
+
+    /// Moves to the page in the given direction, staying on the first or last
+    /// page if there is none.
+    pub fn turn(&mut self, direction: PageDirection) -> &mut Self {
+        self.page = match direction {
+            PageDirection::Previous => self.page.saturating_sub(1),
+            PageDirection::Next => (self.page + 1).min(self.page_count() - 1),
+        };
+
+        self
+    }
+
+    /// Renders the current page into the data of a response.
+    pub fn render<'a>(
+        &self,
+        data: &'a mut CreateInteractionResponseData,
+    ) -> &'a mut CreateInteractionResponseData {
+        let description =
+            self.current_items().iter().map(ToString::to_string).collect::<Vec<_>>().join("\n");
+
+        data.components(|c| c.create_action_row(|row| self.buttons(row)));
+        data.embed(|e| {
+            if let Some(title) = &self.title {
+                e.title(title);
+            }
+
+            e.description(description)
+                .footer(|f| f.text(format!("Page {}/{}", self.page + 1, self.page_count())))
+        })
+    }
+
+    fn buttons<'a>(&self, row: &'a mut CreateActionRow) -> &'a mut CreateActionRow {
+        row.create_button(|b| {
+            b.style(ButtonStyle::Secondary)
+                .label("Previous")
+                .custom_id(PREVIOUS_ID)
+                .disabled(self.page == 0)
+        })
+        .create_button(|b| {
+            b.style(ButtonStyle::Secondary)
+                .label("Next")
+                .custom_id(NEXT_ID)
+                .disabled(self.page + 1 >= self.page_count())
+        })
+    }
+
+    /// Responds to the interaction with the current page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Model`] if the page is too long for a message.
+    /// May also return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Model`]: crate::error::Error::Model
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn send(&self, interaction: &Interaction, http: impl AsRef<Http>) -> Result<()> {
+        interaction
+            .create_interaction_response(http, |r| {
+                r.kind(InteractionResponseType::ChannelMessageWithSource)
+                    .interaction_response_data(|d| self.render(d))
+            })
+            .await
+    }
+
+    /// Moves to the page in the given direction and responds to the
+    /// interaction of the clicked button by updating the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Model`] if the page is too long for a message.
+    /// May also return an [`Error::Http`] if the API returns an error.
+    ///
+    /// [`Error::Model`]: crate::error::Error::Model
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn update_page(
+        &mut self,
+        interaction: &Interaction,
+        http: impl AsRef<Http>,
+        direction: PageDirection,
+    ) -> Result<()> {
+        self.turn(direction);
+
+        interaction
+            .create_interaction_response(http, |r| {
+                r.kind(InteractionResponseType::UpdateMessage)
+                    .interaction_response_data(|d| self.render(d))
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_paginated_response() {
+        let mut pages = PaginatedResponse::new((1..=5).collect(), 2);
+
+        assert_eq!(pages.page_count(), 3);
+        assert_eq!(pages.current_items(), [1, 2]);
+
+        pages.turn(PageDirection::Next).turn(PageDirection::Next).turn(PageDirection::Next);
+        assert_eq!(pages.page(), 2);
+        assert_eq!(pages.current_items(), [5]);
+
+        let mut data = CreateInteractionResponseData::default();
+        pages.render(&mut data);
+
+        assert_eq!(data.0["embeds"][0]["description"], "5");
+        assert_eq!(data.0["embeds"][0]["footer"]["text"], "Page 3/3");
+        assert_eq!(data.0["components"][0]["components"][0]["disabled"], false);
+        assert_eq!(data.0["components"][0]["components"][1]["disabled"], true);
+
+        pages.turn(PageDirection::Previous);
+        assert_eq!(pages.current_items(), [3, 4]);
+        assert_eq!(PageDirection::from_custom_id(NEXT_ID), Some(PageDirection::Next));
+    }
+}
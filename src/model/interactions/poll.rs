@@ -0,0 +1,21 @@
+use crate::model::id::{ChannelId, GuildId, MessageId, UserId};
+
+/// A vote for an answer of a poll, added or removed by a user.
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#message-poll-vote-add).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct PollAnswerVote {
+    /// The Id of the answer voted for.
+    pub answer_id: u32,
+    /// The Id of the user who voted.
+    #[serde(rename = "user_id")]
+    pub voter_id: UserId,
+    /// The Id of the channel of the poll message.
+    pub channel_id: ChannelId,
+    /// The Id of the poll message.
+    pub message_id: MessageId,
+    /// The Id of the guild of the poll message, if it was sent in one.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+}
@@ -0,0 +1,104 @@
+use bitflags::__impl_bitflags;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::internal::prelude::*;
+use crate::model::id::{ApplicationId, SkuId};
+use crate::model::utils::U64Visitor;
+
+/// A premium offering of an application, which users or guilds can buy.
+///
+/// [Discord docs](https://discord.com/developers/docs/monetization/skus#sku-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Sku {
+    /// The Id of the SKU.
+    pub id: SkuId,
+    /// The type of the SKU.
+    #[serde(rename = "type")]
+    pub kind: SkuType,
+    /// The Id of the application the SKU belongs to.
+    pub application_id: ApplicationId,
+    /// The name of the SKU, which is shown to users.
+    pub name: String,
+    /// The URL slug of the SKU, generated by Discord.
+    pub slug: String,
+    /// The flags of the SKU.
+    pub flags: SkuFlags,
+}
+
+/// The type of a [`Sku`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum SkuType {
+    /// A one-time purchase which is permanent.
+    Durable = 2,
+    /// A one-time purchase which can be used up.
+    Consumable = 3,
+    /// A recurring subscription.
+    Subscription = 5,
+    /// A group of subscriptions, generated by Discord.
+    SubscriptionGroup = 6,
+    Unknown = !0,
+}
+
+enum_number!(SkuType {
+    Durable,
+    Consumable,
+    Subscription,
+    SubscriptionGroup
+});
+
+/// The flags of a [`Sku`].
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct SkuFlags {
+    pub bits: u64,
+}
+
+__impl_bitflags! {
+    SkuFlags: u64 {
+        /// The SKU is available for purchase.
+        AVAILABLE = 1 << 2;
+        /// The SKU is a subscription bought for a guild.
+        GUILD_SUBSCRIPTION = 1 << 7;
+        /// The SKU is a subscription bought for a user.
+        USER_SUBSCRIPTION = 1 << 8;
+    }
+}
+
+impl<'de> Deserialize<'de> for SkuFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(SkuFlags::from_bits_truncate(deserializer.deserialize_any(U64Visitor)?))
+    }
+}
+
+impl Serialize for SkuFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize_sku() {
+        let sku: Sku = serde_json::from_value(json!({
+            "id": "1",
+            "type": 5,
+            "application_id": "2",
+            "name": "Premium",
+            "slug": "premium",
+            "flags": 132,
+        }))
+        .unwrap();
+
+        assert_eq!(sku.kind, SkuType::Subscription);
+        assert_eq!(sku.flags, SkuFlags::AVAILABLE | SkuFlags::GUILD_SUBSCRIPTION);
+    }
+}
@@ -0,0 +1,125 @@
+//! Saving the state of interactions that are still being responded to.
+//!
+//! A bot that restarts during a long operation loses the [`Interaction`]s it
+//! was responding to. An [`InteractionSnapshot`] keeps just enough of an
+//! interaction to edit its response, and can be saved to disk and loaded again
+//! after the restart.
+
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::Interaction;
+use crate::builder::EditInteractionResponse;
+use crate::http::Http;
+use crate::internal::prelude::*;
+use crate::model::channel::Message;
+use crate::model::id::ApplicationId;
+use crate::utils;
+
+/// The number of minutes an interaction token stays valid for.
+const TOKEN_LIFETIME_MINUTES: i64 = 15;
+
+/// The state of an [`Interaction`] needed to keep editing its response.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct InteractionSnapshot {
+    /// The token of the interaction.
+    pub token: String,
+    /// The Id of the application the interaction was sent to.
+    pub application_id: ApplicationId,
+    /// The time the interaction was created at.
+    pub created_at: DateTime<Utc>,
+}
+
+impl InteractionSnapshot {
+    /// Whether the interaction token has expired, in which case its response
+    /// can no longer be edited.
+    ///
+    /// Discord keeps interaction tokens valid for 15 minutes.
+    pub fn is_expired(&self) -> bool {
+        Utc::now() - self.created_at > Duration::minutes(TOKEN_LIFETIME_MINUTES)
+    }
+
+    /// Edits the initial response of the interaction.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Model`] if the edited content is too long,
+    /// or if a select menu has more options selected by default than its
+    /// maximum number of values.
+    /// May also return [`Error::Http`] if the API returns an error, such as if
+    /// the snapshot has expired, or an [`Error::Json`] if there is an error
+    /// deserializing the response.
+    ///
+    /// [`Error::Model`]: crate::error::Error::Model
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`Error::Json`]: crate::error::Error::Json
+    pub async fn edit_interaction_response<F>(
+        &self,
+        http: impl AsRef<Http>,
+        f: F,
+    ) -> Result<Message>
+    where
+        F: FnOnce(&mut EditInteractionResponse) -> &mut EditInteractionResponse,
+    {
+        let mut interaction_response = EditInteractionResponse::default();
+        f(&mut interaction_response);
+
+        let map = utils::hashmap_to_json_map(interaction_response.0);
+
+        Message::check_content_length(&map)?;
+        Message::check_embed_length(&map)?;
+        Message::check_components(&map)?;
+
+        http.as_ref()
+            .edit_original_interaction_response(
+                self.application_id.0,
+                &self.token,
+                &Value::Object(map),
+            )
+            .await
+    }
+
+    /// Saves the snapshot to the file at the given path as JSON, replacing its
+    /// contents if it already exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if the file could not be written.
+    ///
+    /// [`Error::Io`]: crate::error::Error::Io
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        fs::write(path, serde_json::to_vec(self)?)?;
+
+        Ok(())
+    }
+
+    /// Loads a snapshot from a file previously written by [`Self::save_to_file`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if the file could not be read, or an
+    /// [`Error::Json`] if it does not contain a snapshot.
+    ///
+    /// [`Error::Io`]: crate::error::Error::Io
+    /// [`Error::Json`]: crate::error::Error::Json
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(serde_json::from_slice(&fs::read(path)?)?)
+    }
+}
+
+impl Interaction {
+    /// Takes a snapshot of the interaction, which can be used to edit its
+    /// response after the bot restarts.
+    pub fn to_snapshot(&self) -> InteractionSnapshot {
+        InteractionSnapshot {
+            token: self.token.expose().to_string(),
+            application_id: self.application_id,
+            created_at: self.id.created_at(),
+        }
+    }
+}
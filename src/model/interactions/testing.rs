@@ -0,0 +1,251 @@
+//! Fixtures for testing interaction handlers.
+//!
+//! Handlers usually only look at a few parts of an [`Interaction`], such as
+//! the invoking user and the option values. [`InteractionBuilder`] creates
+//! interactions with just these parts set, so that handlers can be tested
+//! without a connection to Discord.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use serde_json::Value;
+
+use super::{
+    ApplicationCommandInteractionData,
+    ApplicationCommandInteractionDataOption,
+    ApplicationCommandInteractionDataOptionValue,
+    ApplicationCommandInteractionDataResolved,
+    ApplicationCommandOptionType,
+    Interaction,
+    InteractionType,
+};
+use crate::model::guild::Member;
+use crate::model::id::{ApplicationId, CommandId, GuildId, InteractionId, UserId};
+use crate::model::user::User;
+
+/// The first second of 2015, from which snowflakes are counted.
+const DISCORD_EPOCH: i64 = 1_420_070_400_000;
+
+/// A builder to create [`Interaction`]s for tests.
+///
+/// The interaction is sent by a user in direct messages by default. It is a
+/// [`InteractionType::Ping`] unless a command is set with [`command`], and its
+/// Id is created at the time [`build`] is called, so the token is not expired.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::model::interactions::testing::InteractionBuilder;
+///
+/// let interaction = InteractionBuilder::default()
+///     .guild(1, 2)
+///     .command("echo", 3)
+///     .string_option("to_say", "hello")
+///     .build();
+///
+/// let data = interaction.data.expect("The interaction is a command");
+/// assert_eq!(data.name, "echo");
+/// ```
+///
+/// [`command`]: Self::command
+/// [`build`]: Self::build
+#[derive(Clone, Debug, Default)]
+pub struct InteractionBuilder {
+    guild_id: Option<GuildId>,
+    member: Option<Member>,
+    user: Option<User>,
+    command: Option<(String, CommandId)>,
+    options: Vec<ApplicationCommandInteractionDataOption>,
+    resolved: ApplicationCommandInteractionDataResolved,
+}
+
+impl InteractionBuilder {
+    /// Makes the interaction sent from a guild, by the member with the given
+    /// user Id.
+    pub fn guild(
+        &mut self,
+        guild_id: impl Into<GuildId>,
+        member_id: impl Into<UserId>,
+    ) -> &mut Self {
+        let guild_id = guild_id.into();
+
+        self.guild_id = Some(guild_id);
+        self.member = Some(Member {
+            deaf: false,
+            guild_id,
+            joined_at: None,
+            mute: false,
+            nick: None,
+            roles: Vec::new(),
+            user: test_user(member_id.into()),
+            pending: false,
+            premium_since: None,
+            permissions: None,
+        });
+        self.user = None;
+
+        self
+    }
+
+    /// Makes the interaction sent from direct messages, by the user with the
+    /// given Id.
+    pub fn user(&mut self, user_id: impl Into<UserId>) -> &mut Self {
+        self.guild_id = None;
+        self.member = None;
+        self.user = Some(test_user(user_id.into()));
+
+        self
+    }
+
+    /// Makes the interaction an [`InteractionType::ApplicationCommand`] for
+    /// the command with the given name and Id.
+    pub fn command(&mut self, name: impl ToString, id: impl Into<CommandId>) -> &mut Self {
+        self.command = Some((name.to_string(), id.into()));
+
+        self
+    }
+
+    /// Adds a [`String`] option to the command.
+    ///
+    /// [`String`]: ApplicationCommandOptionType::String
+    pub fn string_option(&mut self, name: impl ToString, value: impl ToString) -> &mut Self {
+        let value = value.to_string();
+
+        self.add_option(
+            name,
+            ApplicationCommandOptionType::String,
+            Value::String(value.clone()),
+            ApplicationCommandInteractionDataOptionValue::String(value),
+        )
+    }
+
+    /// Adds an [`Integer`] option to the command.
+    ///
+    /// [`Integer`]: ApplicationCommandOptionType::Integer
+    pub fn integer_option(&mut self, name: impl ToString, value: i64) -> &mut Self {
+        self.add_option(
+            name,
+            ApplicationCommandOptionType::Integer,
+            Value::from(value),
+            ApplicationCommandInteractionDataOptionValue::Integer(value),
+        )
+    }
+
+    /// Adds a [`User`] option to the command, resolving to the given user.
+    ///
+    /// [`User`]: ApplicationCommandOptionType::User
+    pub fn user_option(&mut self, name: impl ToString, user: User) -> &mut Self {
+        let id = user.id;
+        self.resolved.users.insert(id, user.clone());
+
+        self.add_option(
+            name,
+            ApplicationCommandOptionType::User,
+            Value::String(id.to_string()),
+            ApplicationCommandInteractionDataOptionValue::User(user, None),
+        )
+    }
+
+    fn add_option(
+        &mut self,
+        name: impl ToString,
+        kind: ApplicationCommandOptionType,
+        value: Value,
+        resolved: ApplicationCommandInteractionDataOptionValue,
+    ) -> &mut Self {
+        self.options.push(ApplicationCommandInteractionDataOption {
+            name: name.to_string(),
+            value: Some(value),
+            kind,
+            options: Vec::new(),
+            resolved: Some(resolved),
+            focused: false,
+        });
+
+        self
+    }
+
+    /// Builds the [`Interaction`].
+    pub fn build(&self) -> Interaction {
+        let millis = (Utc::now().timestamp_millis() - DISCORD_EPOCH) as u64;
+
+        let data = self.command.as_ref().map(|(name, id)| ApplicationCommandInteractionData {
+            id: *id,
+            name: name.clone(),
+            options: self.options.clone(),
+            resolved: self.resolved.clone(),
+        });
+
+        let kind = match data {
+            Some(_) => InteractionType::ApplicationCommand,
+            None => InteractionType::Ping,
+        };
+
+        Interaction {
+            id: InteractionId(millis << 22),
+            application_id: ApplicationId(1),
+            kind,
+            data,
+            component_data: None,
+            modal_data: None,
+            guild_id: self.guild_id,
+            channel_id: None,
+            member: self.member.clone(),
+            user: self.user.clone(),
+            token: "token".into(),
+            version: 1,
+            locale: "en-US".to_string(),
+            guild_locale: None,
+            app_permissions: None,
+            entitlements: Vec::new(),
+            authorizing_integration_owners: HashMap::new(),
+        }
+    }
+}
+
+fn test_user(id: UserId) -> User {
+    User {
+        id,
+        bot: false,
+        ..User::default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::interactions::OptionExtract;
+
+    #[test]
+    fn test_interaction_builder() {
+        let target = test_user(UserId(5));
+        let interaction = InteractionBuilder::default()
+            .guild(1, 2)
+            .command("ban", 3)
+            .user_option("target", target)
+            .integer_option("days", 7)
+            .build();
+
+        assert_eq!(interaction.kind, InteractionType::ApplicationCommand);
+        assert_eq!(interaction.guild_id, Some(GuildId(1)));
+        assert!(interaction.user.is_none());
+        assert!(matches!(&interaction.member, Some(m) if m.user.id == UserId(2)));
+        assert!(!interaction.to_snapshot().is_expired());
+
+        let data = interaction.data.as_ref().expect("The interaction is a command");
+        assert!(matches!(data.user("target"), Ok((u, None)) if u.id == UserId(5)));
+        assert!(matches!(data.integer("days"), Ok(7)));
+    }
+
+    #[test]
+    fn test_invoke_user() {
+        let interaction = InteractionBuilder::default().guild(1, 2).build();
+
+        assert_eq!(interaction.invoke_user_id(), Some(UserId(2)));
+
+        let interaction = InteractionBuilder::default().user(3).build();
+
+        assert_eq!(interaction.invoke_user().map(|u| u.id), Some(UserId(3)));
+        assert_eq!(interaction.invoke_user_id(), Some(UserId(3)));
+    }
+}
@@ -22,7 +22,47 @@ pub use super::interactions::*;
 pub use super::invite::*;
 pub use super::misc::*;
 pub use super::permissions::*;
+pub use super::team::*;
 pub use super::user::*;
 pub use super::voice::*;
 pub use super::webhook::*;
-pub use super::*;
+// The contents of `model` are listed by name rather than glob-exported, as a
+// glob would also export this module as `prelude`, next to
+// `interactions::prelude`.
+#[cfg(feature = "unstable_discord_api")]
+pub use super::interactions;
+#[cfg(feature = "voice-model")]
+pub use super::voice_gateway;
+pub use super::{
+    application,
+    channel,
+    error,
+    event,
+    gateway,
+    guild,
+    id,
+    invite,
+    misc,
+    permissions,
+    team,
+    user,
+    voice,
+    webhook,
+    ModelError,
+    Permissions,
+};
+
+// Imports shared by the model sub-modules through this prelude.
+pub(super) use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    result::Result as StdResult,
+};
+
+pub(super) use serde::de::Visitor;
+pub(super) use serde::{Deserialize, Deserializer};
+
+pub(super) use super::utils::*;
+pub(super) use crate::internal::prelude::*;
+#[cfg(feature = "utils")]
+pub(super) use crate::utils::Colour;
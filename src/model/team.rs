@@ -0,0 +1,141 @@
+//! Models about the teams owning applications.
+
+use super::id::{TeamId, UserId};
+use super::user::User;
+
+/// Information about a team owning an application.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Team {
+    /// The hash of the icon of the team.
+    pub icon: Option<String>,
+    /// The Id of the team.
+    pub id: TeamId,
+    /// The members of the team.
+    pub members: Vec<TeamMember>,
+    /// The name of the team.
+    #[serde(default)]
+    pub name: String,
+    /// The user Id of the owner of the team.
+    pub owner_user_id: UserId,
+}
+
+impl Team {
+    /// Gets the member of the team with the given user Id, if they are one.
+    pub fn member(&self, user_id: impl Into<UserId>) -> Option<&TeamMember> {
+        let user_id = user_id.into();
+
+        self.members.iter().find(|member| member.user.id == user_id)
+    }
+
+    /// Whether the user with the given Id is a member of the team who
+    /// accepted their invite.
+    pub fn is_member(&self, user_id: impl Into<UserId>) -> bool {
+        let state = self.member(user_id).map(|member| member.membership_state);
+
+        state == Some(TeamMembershipState::Accepted)
+    }
+
+    /// Whether the user with the given Id is the owner of the team.
+    pub fn is_owner(&self, user_id: impl Into<UserId>) -> bool {
+        self.owner_user_id == user_id.into()
+    }
+}
+
+/// Information about a member of a [`Team`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TeamMember {
+    /// The state of the membership of the member.
+    pub membership_state: TeamMembershipState,
+    /// The permissions of the member on the team.
+    ///
+    /// **Note**: It is always `["*"]` for now.
+    pub permissions: Vec<String>,
+    /// The role of the member on the team.
+    #[serde(default)]
+    pub role: Option<TeamMemberRole>,
+    /// The Id of the team they are a member of.
+    pub team_id: TeamId,
+    /// The user who is a member of the team.
+    pub user: User,
+}
+
+/// The state of the membership of a [`TeamMember`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum TeamMembershipState {
+    /// The user was invited to the team.
+    Invited = 1,
+    /// The user accepted the invite to the team.
+    Accepted = 2,
+    Unknown = !0,
+}
+
+enum_number!(TeamMembershipState {
+    Invited,
+    Accepted
+});
+
+/// The role of a [`TeamMember`], which determines what they can do with the
+/// team's applications.
+#[derive(Copy, Clone, Debug, Deserialize, Hash, Eq, PartialEq, PartialOrd, Ord, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "snake_case")]
+pub enum TeamMemberRole {
+    /// The member can manage the team's applications, members and billing.
+    Admin,
+    /// The member can access and edit the team's applications.
+    Developer,
+    /// The member can view the team's applications.
+    ReadOnly,
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_team_membership() {
+        let team: Team = serde_json::from_value(json!({
+            "icon": null,
+            "id": "1",
+            "name": "Team",
+            "owner_user_id": "2",
+            "members": [
+                {
+                    "membership_state": 2,
+                    "permissions": ["*"],
+                    "role": "admin",
+                    "team_id": "1",
+                    "user": {"id": "2", "username": "owner", "discriminator": "0001", "avatar": null},
+                },
+                {
+                    "membership_state": 1,
+                    "permissions": ["*"],
+                    "role": "support",
+                    "team_id": "1",
+                    "user": {"id": "3", "username": "guest", "discriminator": "0002", "avatar": null},
+                },
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(team.id, TeamId(1));
+        assert!(team.is_member(2));
+        assert!(!team.is_member(3));
+        assert!(team.member(3).is_some());
+        assert!(team.member(4).is_none());
+        assert!(team.is_owner(2));
+        assert!(!team.is_owner(3));
+
+        let roles: Vec<_> = team.members.iter().map(|member| member.role).collect();
+        assert_eq!(roles, vec![Some(TeamMemberRole::Admin), Some(TeamMemberRole::Unknown)]);
+    }
+}
@@ -31,7 +31,7 @@ use crate::http::GuildPagination;
 use crate::http::{CacheHttp, Http};
 #[cfg(feature = "model")]
 use crate::utils;
-use crate::{internal::prelude::*, model::misc::Mentionable};
+use crate::model::misc::Mentionable;
 
 /// Information about the current user.
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
@@ -18,6 +18,15 @@ pub fn default_true() -> bool {
     true
 }
 
+#[cfg(feature = "unstable_discord_api")]
+pub fn deserialize_null_default<'de, D, T>(deserializer: D) -> StdResult<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 pub fn deserialize_emojis<'de, D: Deserializer<'de>>(
     deserializer: D,
 ) -> StdResult<HashMap<EmojiId, Emoji>, D::Error> {
@@ -75,8 +84,8 @@ pub fn deserialize_members<'de, D: Deserializer<'de>>(
 #[cfg(all(feature = "unstable_discord_api", feature = "model"))]
 pub fn deserialize_partial_members_map<'de, D: Deserializer<'de>>(
     deserializer: D,
-) -> StdResult<HashMap<UserId, PartialMember>, D::Error> {
-    let map: HashMap<UserId, PartialMember> = Deserialize::deserialize(deserializer)?;
+) -> StdResult<ResolvedMap<UserId, PartialMember>, D::Error> {
+    let map: ResolvedMap<UserId, PartialMember> = Deserialize::deserialize(deserializer)?;
 
     Ok(map)
 }
@@ -84,8 +93,8 @@ pub fn deserialize_partial_members_map<'de, D: Deserializer<'de>>(
 #[cfg(all(feature = "unstable_discord_api", feature = "model"))]
 pub fn deserialize_users<'de, D: Deserializer<'de>>(
     deserializer: D,
-) -> StdResult<HashMap<UserId, User>, D::Error> {
-    let map: HashMap<UserId, User> = Deserialize::deserialize(deserializer)?;
+) -> StdResult<ResolvedMap<UserId, User>, D::Error> {
+    let map: ResolvedMap<UserId, User> = Deserialize::deserialize(deserializer)?;
 
     Ok(map)
 }
@@ -93,8 +102,8 @@ pub fn deserialize_users<'de, D: Deserializer<'de>>(
 #[cfg(all(feature = "unstable_discord_api", feature = "model"))]
 pub fn deserialize_roles_map<'de, D: Deserializer<'de>>(
     deserializer: D,
-) -> StdResult<HashMap<RoleId, Role>, D::Error> {
-    let map: HashMap<RoleId, Role> = Deserialize::deserialize(deserializer)?;
+) -> StdResult<ResolvedMap<RoleId, Role>, D::Error> {
+    let map: ResolvedMap<RoleId, Role> = Deserialize::deserialize(deserializer)?;
 
     Ok(map)
 }
@@ -102,8 +111,26 @@ pub fn deserialize_roles_map<'de, D: Deserializer<'de>>(
 #[cfg(all(feature = "unstable_discord_api", feature = "model"))]
 pub fn deserialize_channels_map<'de, D: Deserializer<'de>>(
     deserializer: D,
-) -> StdResult<HashMap<ChannelId, PartialChannel>, D::Error> {
-    let map: HashMap<ChannelId, PartialChannel> = Deserialize::deserialize(deserializer)?;
+) -> StdResult<ResolvedMap<ChannelId, PartialChannel>, D::Error> {
+    let map: ResolvedMap<ChannelId, PartialChannel> = Deserialize::deserialize(deserializer)?;
+
+    Ok(map)
+}
+
+#[cfg(all(feature = "unstable_discord_api", feature = "model"))]
+pub fn deserialize_attachments_map<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> StdResult<ResolvedMap<AttachmentId, Attachment>, D::Error> {
+    let map: ResolvedMap<AttachmentId, Attachment> = Deserialize::deserialize(deserializer)?;
+
+    Ok(map)
+}
+
+#[cfg(all(feature = "unstable_discord_api", feature = "model"))]
+pub fn deserialize_messages_map<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> StdResult<ResolvedMap<MessageId, Message>, D::Error> {
+    let map: ResolvedMap<MessageId, Message> = Deserialize::deserialize(deserializer)?;
 
     Ok(map)
 }
@@ -138,6 +165,11 @@ fn set_resolved(
     mut options: &mut ApplicationCommandInteractionDataOption,
     resolved: &ApplicationCommandInteractionDataResolved,
 ) {
+    // The value of a focused option is the partial input of the user.
+    if options.focused {
+        return;
+    }
+
     if let Some(ref value) = options.value {
         let string = value.as_str();
 
@@ -173,6 +205,18 @@ fn set_resolved(
             ApplicationCommandOptionType::Integer => {
                 Some(ApplicationCommandInteractionDataOptionValue::Integer(value.as_i64().unwrap()))
             },
+            ApplicationCommandOptionType::Mentionable => string
+                .and_then(|s| s.parse::<u64>().ok())
+                .and_then(|id| resolve_mentionable(id, resolved)),
+            ApplicationCommandOptionType::Attachment => string
+                .and_then(|s| s.parse::<u64>().ok())
+                .and_then(|id| resolved.attachments.get(&AttachmentId::from(id)))
+                .map(|attachment| {
+                    ApplicationCommandInteractionDataOptionValue::Attachment(attachment.to_owned())
+                }),
+            ApplicationCommandOptionType::Number => {
+                value.as_f64().map(ApplicationCommandInteractionDataOptionValue::Number)
+            },
             ApplicationCommandOptionType::Boolean => Some(
                 ApplicationCommandInteractionDataOptionValue::Boolean(value.as_bool().unwrap()),
             ),
@@ -181,6 +225,26 @@ fn set_resolved(
     }
 }
 
+/// Resolves the value of a [`Mentionable`] option, which is either a user or a
+/// role.
+///
+/// [`Mentionable`]: ApplicationCommandOptionType::Mentionable
+#[cfg(all(feature = "unstable_discord_api", feature = "model"))]
+fn resolve_mentionable(
+    id: u64,
+    resolved: &ApplicationCommandInteractionDataResolved,
+) -> Option<ApplicationCommandInteractionDataOptionValue> {
+    let user_id = UserId(id);
+
+    if let Some(user) = resolved.users.get(&user_id) {
+        let member = resolved.members.get(&user_id).cloned();
+
+        return Some(ApplicationCommandInteractionDataOptionValue::User(user.clone(), member));
+    }
+
+    resolved.roles.get(&RoleId(id)).cloned().map(ApplicationCommandInteractionDataOptionValue::Role)
+}
+
 #[cfg(all(feature = "unstable_discord_api", feature = "model"))]
 fn loop_resolved(
     options: &mut ApplicationCommandInteractionDataOption,
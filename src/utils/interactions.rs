@@ -0,0 +1,313 @@
+//! Utilities for working with interactions and application commands.
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use serde::Deserialize;
+
+use crate::builder::{CreateApplicationCommand, CreateApplicationCommandOption};
+use crate::model::interactions::ApplicationCommandOptionType;
+
+/// An error returned when building application commands from a TOML
+/// definition.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TomlCommandError {
+    /// The input was not valid TOML, or did not match the command schema.
+    Toml(toml::de::Error),
+}
+
+impl From<toml::de::Error> for TomlCommandError {
+    fn from(e: toml::de::Error) -> Self {
+        TomlCommandError::Toml(e)
+    }
+}
+
+impl Display for TomlCommandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            TomlCommandError::Toml(inner) => write!(f, "Invalid command definition: {}", inner),
+        }
+    }
+}
+
+impl StdError for TomlCommandError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            TomlCommandError::Toml(inner) => Some(inner),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TomlCommands {
+    #[serde(default)]
+    commands: Vec<TomlCommand>,
+}
+
+#[derive(Deserialize)]
+struct TomlCommand {
+    name: String,
+    description: String,
+    default_permission: Option<bool>,
+    #[serde(default)]
+    options: Vec<TomlOption>,
+}
+
+#[derive(Deserialize)]
+struct TomlOption {
+    kind: TomlOptionKind,
+    name: String,
+    description: String,
+    #[serde(default)]
+    required: bool,
+    #[serde(default)]
+    choices: Vec<TomlChoice>,
+    #[serde(default)]
+    options: Vec<TomlOption>,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum TomlOptionKind {
+    SubCommand,
+    SubCommandGroup,
+    String,
+    Integer,
+    Boolean,
+    User,
+    Channel,
+    Role,
+    Mentionable,
+    Number,
+    Attachment,
+}
+
+impl From<TomlOptionKind> for ApplicationCommandOptionType {
+    fn from(kind: TomlOptionKind) -> Self {
+        match kind {
+            TomlOptionKind::SubCommand => ApplicationCommandOptionType::SubCommand,
+            TomlOptionKind::SubCommandGroup => ApplicationCommandOptionType::SubCommandGroup,
+            TomlOptionKind::String => ApplicationCommandOptionType::String,
+            TomlOptionKind::Integer => ApplicationCommandOptionType::Integer,
+            TomlOptionKind::Boolean => ApplicationCommandOptionType::Boolean,
+            TomlOptionKind::User => ApplicationCommandOptionType::User,
+            TomlOptionKind::Channel => ApplicationCommandOptionType::Channel,
+            TomlOptionKind::Role => ApplicationCommandOptionType::Role,
+            TomlOptionKind::Mentionable => ApplicationCommandOptionType::Mentionable,
+            TomlOptionKind::Number => ApplicationCommandOptionType::Number,
+            TomlOptionKind::Attachment => ApplicationCommandOptionType::Attachment,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TomlChoice {
+    name: String,
+    value: TomlChoiceValue,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TomlChoiceValue {
+    Integer(i32),
+    String(String),
+}
+
+impl TomlCommand {
+    fn into_builder(self) -> CreateApplicationCommand {
+        let mut command = CreateApplicationCommand::default();
+        command.name(self.name).description(self.description);
+
+        if let Some(default_permission) = self.default_permission {
+            #[allow(deprecated)]
+            command.default_permission(default_permission);
+        }
+
+        for option in self.options {
+            command.add_option(option.into_builder());
+        }
+
+        command
+    }
+}
+
+impl TomlOption {
+    fn into_builder(self) -> CreateApplicationCommandOption {
+        let mut option = CreateApplicationCommandOption::default();
+        option
+            .kind(self.kind.into())
+            .name(self.name)
+            .description(self.description)
+            .required(self.required);
+
+        for choice in self.choices {
+            match choice.value {
+                TomlChoiceValue::Integer(value) => option.add_int_choice(choice.name, value),
+                TomlChoiceValue::String(value) => option.add_string_choice(choice.name, value),
+            };
+        }
+
+        for sub_option in self.options {
+            option.add_sub_option(sub_option.into_builder());
+        }
+
+        option
+    }
+}
+
+/// Builds a [`CreateApplicationCommand`] from a TOML definition.
+///
+/// The definition uses the same field names as Discord's application command
+/// object. Option kinds are written in `snake_case`, e.g. `sub_command` or
+/// `string`. Choices may have either an integer or a string value, and
+/// nested options are only meaningful for sub-commands and sub-command groups.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::interactions::build_command_from_toml;
+///
+/// let command = build_command_from_toml(
+///     r#"
+///     name = "echo"
+///     description = "What is said is echoed"
+///
+///     [[options]]
+///     kind = "string"
+///     name = "to_say"
+///     description = "What will be echoed"
+///     required = true
+///     "#,
+/// )
+/// .expect("Invalid command definition");
+///
+/// assert_eq!(command.0["name"], "echo");
+/// ```
+///
+/// # Errors
+///
+/// Returns a [`TomlCommandError::Toml`] if the input is not valid TOML, or if
+/// it is missing a required field or uses an unknown option kind.
+pub fn build_command_from_toml(toml: &str) -> Result<CreateApplicationCommand, TomlCommandError> {
+    let command: TomlCommand = toml::from_str(toml)?;
+
+    Ok(command.into_builder())
+}
+
+/// Builds several [`CreateApplicationCommand`]s from a TOML definition.
+///
+/// Each command is written as an entry of the `commands` array of tables,
+/// following the same schema as [`build_command_from_toml`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::interactions::build_commands_from_toml;
+///
+/// let commands = build_commands_from_toml(
+///     r#"
+///     [[commands]]
+///     name = "ping"
+///     description = "A simple ping command"
+///
+///     [[commands]]
+///     name = "pong"
+///     description = "A simple pong command"
+///     "#,
+/// )
+/// .expect("Invalid command definitions");
+///
+/// assert_eq!(commands.len(), 2);
+/// ```
+///
+/// # Errors
+///
+/// Returns a [`TomlCommandError::Toml`] under the same conditions as
+/// [`build_command_from_toml`].
+pub fn build_commands_from_toml(
+    toml: &str,
+) -> Result<Vec<CreateApplicationCommand>, TomlCommandError> {
+    let commands: TomlCommands = toml::from_str(toml)?;
+
+    Ok(commands.commands.into_iter().map(TomlCommand::into_builder).collect())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_command_from_toml() {
+        let command = build_command_from_toml(
+            r#"
+            name = "animal"
+            description = "Picks an animal"
+
+            [[options]]
+            kind = "sub_command"
+            name = "pick"
+            description = "Picks one"
+
+            [[options.options]]
+            kind = "string"
+            name = "kind"
+            description = "The kind of animal"
+            required = true
+
+            [[options.options.choices]]
+            name = "Cat"
+            value = "cat"
+
+            [[options.options.choices]]
+            name = "Dog"
+            value = 2
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(command.0["name"], "animal");
+        assert_eq!(command.0["description"], "Picks an animal");
+        assert_eq!(
+            command.0["options"],
+            json!([{
+                "type": 1,
+                "name": "pick",
+                "description": "Picks one",
+                "required": false,
+                "options": [{
+                    "type": 3,
+                    "name": "kind",
+                    "description": "The kind of animal",
+                    "required": true,
+                    "choices": [
+                        {"name": "Cat", "value": "cat"},
+                        {"name": "Dog", "value": 2},
+                    ],
+                }],
+            }])
+        );
+    }
+
+    #[test]
+    fn test_command_from_toml_errors() {
+        assert!(build_command_from_toml("description = \"No name\"").is_err());
+        assert!(build_command_from_toml(
+            r#"
+            name = "bad"
+            description = "Unknown option kind"
+
+            [[options]]
+            kind = "float"
+            name = "value"
+            description = "A value"
+            "#,
+        )
+        .is_err());
+    }
+}
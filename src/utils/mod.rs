@@ -3,6 +3,12 @@
 
 mod colour;
 mod custom_message;
+#[cfg(all(feature = "builder", feature = "unstable_discord_api", feature = "toml"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "builder", feature = "unstable_discord_api", feature = "toml")))
+)]
+pub mod interactions;
 mod message_builder;
 #[cfg(feature = "client")]
 mod parse;